@@ -1,3 +1,4 @@
+use crate::builder::validator::Validator;
 use crate::Type;
 
 /// Defines an Arg within [Context](struct@crate::Context)
@@ -45,6 +46,81 @@ use crate::Type;
 /// );
 /// # }
 /// ```
+/// A variadic argument takes every positional argument left over, e.g. `cp SRC... DST`.
+/// Mandatory variadic arguments take one or more values, otherwise zero or more.
+/// ```rust
+/// use parsin::{parse_from, try_parse_from, Arg, Context, Flag, Type};
+///
+/// # fn main() {
+/// let ctx = Context::from((
+///     &[
+///         Arg::from(("src", Type::Str, "Files to copy", true, None)).variadic(),
+///         Arg::from(("dst", Type::Str, "Destination", true, None)),
+///     ],
+///     &[] as &[Flag; 0],
+/// ));
+/// let parsed = parse_from(&ctx, ["a", "b", "dir"]);
+/// assert_eq!(parsed.arguments["src"].unwrap_list().len(), 2);
+/// assert_eq!(parsed.arguments["dst"].unwrap_str(), "dir");
+///
+/// let help = try_parse_from(&ctx, ["--help"]).unwrap_err().diagnosis();
+/// assert!(help.contains("[OPTIONS] <SRC>... <DST>"));
+/// assert!(help.contains("<SRC>...   Files to copy"));
+///
+/// let ctx = Context::from((
+///     &[Arg::from(("files", Type::Path, "Files to read", false, None)).variadic()],
+///     &[] as &[Flag; 0],
+/// ));
+/// let parsed = parse_from(&ctx, [] as [&str; 0]);
+/// assert!(parsed.arguments["files"].unwrap_list().is_empty());
+///
+/// // a context holds at most one variadic argument
+/// let ctx = Context::from((
+///     &[
+///         Arg::from(("src", Type::Str, "Files to copy", true, None)).variadic(),
+///         Arg::from(("dst", Type::Str, "Destinations", true, None)).variadic(),
+///     ],
+///     &[] as &[Flag; 0],
+/// ));
+/// assert!(ctx.validate().is_err());
+/// # }
+/// ```
+/// An argument may be mandatory depending on other flags and arguments, referred to by name.
+/// ```rust
+/// use parsin::{try_parse_from, Arg, Context, Flag, Type};
+/// use parsin::errors::ErrorKind;
+///
+/// # fn main() {
+/// let ctx = Context::from((
+///     &[Arg::from(("file", Type::Path, "File to read", false, None)).required_unless("--stdin")],
+///     &[Flag::from(("--stdin", Type::Bool, "Reads from stdin", false, None))],
+/// ));
+/// let error = try_parse_from(&ctx, [] as [&str; 0]).unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::MissingArgument);
+/// assert!(try_parse_from(&ctx, ["--stdin"]).is_ok());
+/// # }
+/// ```
+/// Values given to an argument can be checked by validators, which reject a value
+/// by returning why.
+/// ```rust
+/// use parsin::{try_parse_from, Arg, Context, Flag, Type};
+/// use parsin::errors::ErrorKind;
+///
+/// # fn main() {
+/// let ctx = Context::from((
+///     &[Arg::from(("name", Type::Str, "Your name", true, None)).validator(|name| {
+///         match name.chars().all(char::is_alphabetic) {
+///             true => Ok(()),
+///             false => Err(String::from("only letters are allowed")),
+///         }
+///     })],
+///     &[] as &[Flag; 0],
+/// ));
+/// let error = try_parse_from(&ctx, ["J0hn"]).unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::InvalidValue);
+/// assert!(try_parse_from(&ctx, ["John"]).is_ok());
+/// # }
+/// ```
 #[derive(Debug, Clone)]
 pub struct Arg {
     /// The name of the argument
@@ -57,6 +133,30 @@ pub struct Arg {
     pub(crate) is_mandatory: bool,
     /// Introduce a string default value, that'll later get parse during execution
     pub(crate) default: Option<String>,
+    /// Signals if the argument takes every positional argument left over
+    pub(crate) variadic: bool,
+    /// Environment variable consulted when the argument is not given
+    pub(crate) env: Option<String>,
+    /// Names of the flags and arguments that must be given along with this one
+    pub(crate) requires: Vec<String>,
+    /// Names of the flags and arguments that cannot be given along with this one
+    pub(crate) conflicts_with: Vec<String>,
+    /// Names of the flags and arguments making this one mandatory when given
+    pub(crate) required_if: Vec<String>,
+    /// Names of the flags and arguments making this one mandatory when none is given
+    pub(crate) required_unless: Vec<String>,
+    /// Closures checking every value given to the argument
+    pub(crate) validators: Vec<Validator>,
+    /// The only values the argument accepts, when not empty
+    pub(crate) possible_values: Vec<String>,
+    /// Signals if the possible values are matched regardless of case
+    pub(crate) ignore_case: bool,
+    /// Least value a `Type::Int` argument accepts
+    pub(crate) min_value: Option<i32>,
+    /// Greatest value a `Type::Int` argument accepts
+    pub(crate) max_value: Option<i32>,
+    /// Signals if a `Type::Float` argument accepts NaN and infinite numbers
+    pub(crate) allow_non_finite: bool,
 }
 
 impl Arg {
@@ -73,9 +173,115 @@ impl Arg {
             help,
             is_mandatory,
             default,
+            variadic: false,
+            env: None,
+            requires: Vec::new(),
+            conflicts_with: Vec::new(),
+            required_if: Vec::new(),
+            required_unless: Vec::new(),
+            validators: Vec::new(),
+            possible_values: Vec::new(),
+            ignore_case: false,
+            min_value: None,
+            max_value: None,
+            allow_non_finite: false,
         }
     }
 
+    /// Makes the argument take every positional argument left over,
+    /// collecting them into a [`Value::List`](enum@crate::parser::Value)
+    pub fn variadic(mut self) -> Self {
+        self.variadic = true;
+        self
+    }
+
+    /// Names an environment variable whose value is taken when the argument
+    /// is not given, before falling back onto the default value
+    pub fn env(mut self, var: &str) -> Self {
+        self.env = Some(var.to_string());
+        self
+    }
+
+    /// Requires the flag or argument named `name` to be given whenever this argument is given
+    pub fn requires(mut self, name: &str) -> Self {
+        self.requires.push(name.to_string());
+        self
+    }
+
+    /// Forbids the flag or argument named `name` from being given along with this argument
+    pub fn conflicts_with(mut self, name: &str) -> Self {
+        self.conflicts_with.push(name.to_string());
+        self
+    }
+
+    /// Makes the argument mandatory whenever the flag or argument named `name` is given
+    pub fn required_if(mut self, name: &str) -> Self {
+        self.required_if.push(name.to_string());
+        self
+    }
+
+    /// Makes the argument mandatory unless the flag or argument named `name` is given,
+    /// when called more than once, any of them being given is enough
+    pub fn required_unless(mut self, name: &str) -> Self {
+        self.required_unless.push(name.to_string());
+        self
+    }
+
+    /// Attaches a closure checking every value given to the argument, which returns
+    /// why a value was rejected. Validators run in the order they were attached
+    /// and check the default value as well
+    pub fn validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.validators.push(Validator::new(validator));
+        self
+    }
+
+    /// Restricts the values the argument accepts, e.g. `&["json", "yaml", "text"]`,
+    /// which the default value must be one of
+    pub fn possible_values(mut self, values: &[&str]) -> Self {
+        self.possible_values = values.iter().map(|v| v.to_string()).collect();
+        self
+    }
+
+    /// Matches the possible values regardless of case, the value is
+    /// then spelled the way it was defined, e.g. `JSON` yields `json`
+    pub fn ignore_case(mut self) -> Self {
+        self.ignore_case = true;
+        self
+    }
+
+    /// Least value a `Type::Int` argument accepts, inclusive
+    pub fn min_value(mut self, min: i32) -> Self {
+        self.min_value = Some(min);
+        self
+    }
+
+    /// Greatest value a `Type::Int` argument accepts, inclusive
+    pub fn max_value(mut self, max: i32) -> Self {
+        self.max_value = Some(max);
+        self
+    }
+
+    /// Allows a `Type::Float` argument to take NaN and infinite numbers, e.g. `inf`
+    /// ```rust
+    /// use parsin::{try_parse_from, Arg, Context, Flag, Type};
+    ///
+    /// # fn main() {
+    /// let ctx = Context::from((
+    ///     &[Arg::from(("lower", Type::Float, "Lower bound", true, None)).allow_non_finite()],
+    ///     &[] as &[Flag; 0],
+    /// ));
+    /// let parsed = try_parse_from(&ctx, ["-inf"]).unwrap();
+    /// assert_eq!(*parsed.arguments["lower"].unwrap_float(), f64::NEG_INFINITY);
+    /// # }
+    /// ```
+    pub fn allow_non_finite(mut self) -> Self {
+        self.allow_non_finite = true;
+        self
+    }
+
     pub fn get_name(&self) -> String {
         self.name.clone()
     }
@@ -95,6 +301,50 @@ impl Arg {
     pub fn get_default(&self) -> Option<String> {
         self.default.clone()
     }
+
+    pub fn is_variadic(&self) -> bool {
+        self.variadic
+    }
+
+    pub fn get_env(&self) -> Option<String> {
+        self.env.clone()
+    }
+
+    pub fn get_requires(&self) -> Vec<String> {
+        self.requires.clone()
+    }
+
+    pub fn get_conflicts_with(&self) -> Vec<String> {
+        self.conflicts_with.clone()
+    }
+
+    pub fn get_required_if(&self) -> Vec<String> {
+        self.required_if.clone()
+    }
+
+    pub fn get_required_unless(&self) -> Vec<String> {
+        self.required_unless.clone()
+    }
+
+    pub fn get_possible_values(&self) -> Vec<String> {
+        self.possible_values.clone()
+    }
+
+    pub fn is_ignoring_case(&self) -> bool {
+        self.ignore_case
+    }
+
+    pub fn get_min_value(&self) -> Option<i32> {
+        self.min_value
+    }
+
+    pub fn get_max_value(&self) -> Option<i32> {
+        self.max_value
+    }
+
+    pub fn allows_non_finite(&self) -> bool {
+        self.allow_non_finite
+    }
 }
 
 impl From<&(&str, Type, &str, bool, Option<&str>)> for Arg {
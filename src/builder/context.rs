@@ -2,8 +2,12 @@ use crate::errors::Error;
 use crate::errors::ErrorKind;
 use crate::Arg;
 use crate::Flag;
+use crate::Group;
+use crate::Subcommand;
 use crate::Type;
 
+use std::path::PathBuf;
+
 /// A struct defining arbitrary arguments and flags
 /// to then pass onto the [parse](fn@crate::parse) function
 #[derive(Debug, Clone)]
@@ -12,6 +16,26 @@ pub struct Context {
     pub(crate) args: Vec<Arg>,
     /// Defined arbitrary flags you want
     pub(crate) flags: Vec<Flag>,
+    /// Defined subcommands, each with their own context
+    pub(crate) subcommands: Vec<Subcommand>,
+    /// Defined groups of mutually exclusive flags and arguments
+    pub(crate) groups: Vec<Group>,
+    /// Signals if the arguments after `--` are captured as they are
+    /// instead of being treated as positional arguments
+    pub(crate) capture_trailing: bool,
+    /// Signals if positional arguments given beyond the defined ones
+    /// are collected instead of rejected
+    pub(crate) allow_extra_arguments: bool,
+    /// Signals if flags not defined are collected instead of rejected
+    pub(crate) allow_unknown_flags: bool,
+    /// Signals if long flags may be abbreviated to an unambiguous prefix
+    pub(crate) allow_prefix_matching: bool,
+    /// Signals if `@path` arguments are replaced by the contents of the file
+    pub(crate) expand_response_files: bool,
+    /// Name of the flag giving the path of the configuration file
+    pub(crate) config_flag: Option<String>,
+    /// Locations searched for the configuration file, in order
+    pub(crate) config_paths: Vec<PathBuf>,
 }
 
 impl Context {
@@ -19,6 +43,15 @@ impl Context {
         Self {
             args: Vec::new(),
             flags: Vec::new(),
+            subcommands: Vec::new(),
+            groups: Vec::new(),
+            capture_trailing: false,
+            allow_extra_arguments: false,
+            allow_unknown_flags: false,
+            allow_prefix_matching: false,
+            expand_response_files: false,
+            config_flag: None,
+            config_paths: Vec::new(),
         }
     }
 
@@ -32,6 +65,311 @@ impl Context {
         self.flags.clone()
     }
 
+    /// getter
+    pub fn subcommands(&self) -> Vec<Subcommand> {
+        self.subcommands.clone()
+    }
+
+    /// getter
+    pub fn groups(&self) -> Vec<Group> {
+        self.groups.clone()
+    }
+
+    /// getter
+    pub fn captures_trailing(&self) -> bool {
+        self.capture_trailing
+    }
+
+    /// When enabled, every argument after a standalone `--` is captured
+    /// as it is into [`ParsedArguments.trailing`](struct@crate::parser::ParsedArguments),
+    /// e.g. to forward them to a child process
+    /// ```rust
+    /// use parsin::{parse_from, try_parse_from, Context, Type};
+    /// use parsin::errors::ErrorKind;
+    ///
+    /// # fn main() {
+    /// let mut ctx = Context::from((
+    ///     &[("program", Type::Str, "Program to run", true, None)],
+    ///     &[("--verbose", Type::Bool, "Displays more information", false, None)],
+    /// ));
+    /// ctx.set_capture_trailing(true);
+    /// let parsed = parse_from(&ctx, ["cargo", "--", "build", "--verbose"]);
+    /// assert_eq!(parsed.trailing, vec!["build", "--verbose"]);
+    /// assert!(parsed.flags["--verbose"].is_null());
+    ///
+    /// // `--help` is no flag after `--`, unless that `--` escapes the value of a flag
+    /// let ctx = Context::from((&[], &[("--name", Type::Str, "Your name", false, None)]));
+    /// let error = try_parse_from(&ctx, ["--", "--help"]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::UnexpectedArgument);
+    /// let error = try_parse_from(&ctx, ["--name", "--", "--x", "--help"]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::WantsHelp);
+    /// # }
+    /// ```
+    pub fn set_capture_trailing(&mut self, capture: bool) {
+        self.capture_trailing = capture;
+    }
+
+    /// getter
+    pub fn allows_extra_arguments(&self) -> bool {
+        self.allow_extra_arguments
+    }
+
+    /// When enabled, positional arguments given beyond the ones defined are collected into
+    /// [`ParsedArguments.extra_arguments`](struct@crate::parser::ParsedArguments)
+    /// instead of being rejected
+    /// ```rust
+    /// use parsin::{try_parse_from, Context, Type};
+    /// use parsin::errors::ErrorKind;
+    ///
+    /// # fn main() {
+    /// let mut ctx = Context::from((
+    ///     &[("file", Type::Str, "File to read", true, None)],
+    ///     &[("--verbose", Type::Bool, "Displays more information", false, None)],
+    /// ));
+    /// let error = try_parse_from(&ctx, ["a.txt", "--verbose", "b.txt"]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::UnexpectedArgument);
+    /// assert!(error.diagnosis().contains("`b.txt` found at position 3"));
+    ///
+    /// ctx.set_allow_extra_arguments(true);
+    /// let parsed = try_parse_from(&ctx, ["a.txt", "--verbose", "b.txt", "c.txt"]).unwrap();
+    /// assert_eq!(parsed.arguments["file"].unwrap_str(), "a.txt");
+    /// assert_eq!(parsed.extra_arguments, vec!["b.txt", "c.txt"]);
+    /// # }
+    /// ```
+    pub fn set_allow_extra_arguments(&mut self, allow: bool) {
+        self.allow_extra_arguments = allow;
+    }
+
+    /// getter
+    pub fn allows_unknown_flags(&self) -> bool {
+        self.allow_unknown_flags
+    }
+
+    /// When enabled, flags not defined within the context are collected in order into
+    /// [`ParsedArguments.unknown_flags`](struct@crate::parser::ParsedArguments)
+    /// instead of being rejected, e.g. to forward them to an inner tool.
+    ///
+    /// The argument following an unknown flag is taken as its value unless it looks
+    /// like a flag itself, use the `--flag=value` syntax to avoid any ambiguity.
+    /// ```rust
+    /// use parsin::{parse_from, Context, Type};
+    ///
+    /// # fn main() {
+    /// let mut ctx = Context::from((
+    ///     &[("file", Type::Str, "File to compile", true, None)],
+    ///     &[("--verbose", Type::Bool, "Displays more information", false, None)],
+    /// ));
+    /// ctx.set_allow_unknown_flags(true);
+    /// let parsed = parse_from(&ctx, ["--opt-level=3", "main.c", "--verbose", "-W", "all"]);
+    /// assert_eq!(parsed.unknown_flags, vec!["--opt-level=3", "-W", "all"]);
+    /// assert_eq!(parsed.arguments["file"].unwrap_str(), "main.c");
+    /// # }
+    /// ```
+    /// Otherwise, unknown flags are rejected along with a suggestion, while negative
+    /// numbers are still taken as positional arguments
+    /// ```rust
+    /// use parsin::{try_parse_from, Context, Type};
+    /// use parsin::errors::ErrorKind;
+    ///
+    /// # fn main() {
+    /// let ctx = Context::from((
+    ///     &[("offset", Type::Int, "Lines to skip", false, None)],
+    ///     &[("--verbose", Type::Bool, "Displays more information", false, None)],
+    /// ));
+    /// let error = try_parse_from(&ctx, ["--verbsoe"]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::UnknownFlag);
+    /// assert!(error.diagnosis().contains("a similar one exists: `--verbose`"));
+    ///
+    /// let parsed = try_parse_from(&ctx, ["-5"]).unwrap();
+    /// assert_eq!(*parsed.arguments["offset"].unwrap_int(), -5);
+    /// # }
+    /// ```
+    pub fn set_allow_unknown_flags(&mut self, allow: bool) {
+        self.allow_unknown_flags = allow;
+    }
+
+    /// getter
+    pub fn allows_prefix_matching(&self) -> bool {
+        self.allow_prefix_matching
+    }
+
+    /// When enabled, long flags may be abbreviated to any unambiguous prefix,
+    /// e.g. `--verb` for `--verbose`
+    /// ```rust
+    /// use parsin::{try_parse_from, Context, Type};
+    /// use parsin::errors::ErrorKind;
+    ///
+    /// # fn main() {
+    /// let mut ctx = Context::from((
+    ///     &[],
+    ///     &[
+    ///         ("--verbose", Type::Bool, "Displays more information", false, None),
+    ///         ("--version", Type::Bool, "Displays the version", false, None),
+    ///         ("--output", Type::Str, "File to write", false, None),
+    ///     ],
+    /// ));
+    /// ctx.set_allow_prefix_matching(true);
+    /// let parsed = try_parse_from(&ctx, ["--verb"]).unwrap();
+    /// assert!(*parsed.flags["--verbose"].unwrap_bool());
+    ///
+    /// let error = try_parse_from(&ctx, ["--ver"]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::AmbiguousFlag);
+    ///
+    /// // an abbreviated flag is not taken as the value of another one
+    /// let error = try_parse_from(&ctx, ["--output", "--verb"]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::MissingValue);
+    /// # }
+    /// ```
+    pub fn set_allow_prefix_matching(&mut self, allow: bool) {
+        self.allow_prefix_matching = allow;
+    }
+
+    /// getter
+    pub fn expands_response_files(&self) -> bool {
+        self.expand_response_files
+    }
+
+    /// When enabled, every `@path` argument is replaced by the arguments contained within
+    /// the file, which are separated by whitespace and may be quoted. Response files may
+    /// include further response files, as long as they do not include themselves
+    /// ```rust
+    /// use parsin::{try_parse_from, Context, Type};
+    ///
+    /// # fn main() {
+    /// let path = std::env::temp_dir().join("parsin-response-file-doc.txt");
+    /// std::fs::write(&path, "--include 'my dir'\n--verbose").unwrap();
+    ///
+    /// let mut ctx = Context::from((
+    ///     &[],
+    ///     &[
+    ///         ("--include", Type::Str, "Directory to include", false, None),
+    ///         ("--verbose", Type::Bool, "Displays more information", false, None),
+    ///     ],
+    /// ));
+    /// ctx.set_expand_response_files(true);
+    /// let parsed = try_parse_from(&ctx, [format!("@{}", path.display())]).unwrap();
+    /// assert_eq!(parsed.flags["--include"].unwrap_str(), "my dir");
+    /// assert!(*parsed.flags["--verbose"].unwrap_bool());
+    /// # std::fs::remove_file(&path).unwrap();
+    /// # }
+    /// ```
+    /// Errors point at the response file and the line at fault
+    /// ```rust
+    /// use parsin::{try_parse_from, Context};
+    /// use parsin::errors::ErrorKind;
+    ///
+    /// # fn main() {
+    /// let mut ctx = Context::new();
+    /// ctx.set_expand_response_files(true);
+    ///
+    /// let path = std::env::temp_dir().join("parsin-response-file-cycle.txt");
+    /// std::fs::write(&path, format!("--verbose\n@{}", path.display())).unwrap();
+    /// let error = try_parse_from(&ctx, [format!("@{}", path.display())]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::ResponseFile);
+    /// assert!(error.diagnosis().contains("at line 2) includes itself"));
+    ///
+    /// std::fs::write(&path, "--verbose\n--include 'my dir").unwrap();
+    /// let error = try_parse_from(&ctx, [format!("@{}", path.display())]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::ResponseFile);
+    /// assert!(error.diagnosis().ends_with("at line 2"));
+    /// # std::fs::remove_file(&path).unwrap();
+    ///
+    /// // response files may include one another up to 16 levels deep
+    /// let chain: Vec<_> = (0..=16)
+    ///     .map(|i| std::env::temp_dir().join(format!("parsin-response-file-{}.txt", i)))
+    ///     .collect();
+    /// for pair in chain.windows(2) {
+    ///     std::fs::write(&pair[0], format!("@{}", pair[1].display())).unwrap();
+    /// }
+    /// std::fs::write(&chain[16], "--verbose").unwrap();
+    /// let error = try_parse_from(&ctx, [format!("@{}", chain[0].display())]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::ResponseFile);
+    /// assert!(error.diagnosis().contains("maximum nesting depth of 16"));
+    /// # for path in chain.iter() {
+    /// #     std::fs::remove_file(path).unwrap();
+    /// # }
+    /// # }
+    /// ```
+    pub fn set_expand_response_files(&mut self, expand: bool) {
+        self.expand_response_files = expand;
+    }
+
+    /// getter
+    pub fn get_config_flag(&self) -> Option<String> {
+        self.config_flag.clone()
+    }
+
+    /// getter
+    pub fn get_config_paths(&self) -> Vec<PathBuf> {
+        self.config_paths.clone()
+    }
+
+    /// Declares the flag giving the path of a configuration file, which fills the flags
+    /// not given on the command line. The flag must be either `Type::Path` or `Type::Str`.
+    ///
+    /// The file holds `key = value` lines, keys being flag names without their leading
+    /// dashes. Comments start with `#` or `;`, either on their own line or after a value,
+    /// and `[section]` headers are skipped. A value given on the command line wins over
+    /// its environment variable, which wins over the configuration file, which wins
+    /// over the default value
+    /// ```rust
+    /// use parsin::{try_parse_from, Context, Type};
+    ///
+    /// # fn main() {
+    /// let path = std::env::temp_dir().join("parsin-config-doc.conf");
+    /// std::fs::write(
+    ///     &path,
+    ///     "# server settings\n[server]\nport = 8080 ; default\nhost = \"0.0.0.0\" # any\n",
+    /// )
+    /// .unwrap();
+    ///
+    /// let mut ctx = Context::from((
+    ///     &[],
+    ///     &[
+    ///         ("--config", Type::Path, "Configuration file", false, None),
+    ///         ("--port", Type::Int, "Port to listen on", false, Some("80")),
+    ///         ("--host", Type::Str, "Host to listen on", false, None),
+    ///     ],
+    /// ));
+    /// ctx.set_config_flag("--config").unwrap();
+    /// let config = path.to_str().unwrap();
+    /// let parsed = try_parse_from(&ctx, ["--config", config, "--port", "3000"]).unwrap();
+    /// assert_eq!(*parsed.flags["--port"].unwrap_int(), 3000);
+    /// assert_eq!(parsed.flags["--host"].unwrap_str(), "0.0.0.0");
+    /// let parsed = try_parse_from(&ctx, ["--config", config]).unwrap();
+    /// assert_eq!(*parsed.flags["--port"].unwrap_int(), 8080);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// # }
+    /// ```
+    pub fn set_config_flag(&mut self, name: &str) -> Result<(), Error> {
+        let flag: Flag = match self.get_flag(name) {
+            Some(flag) => flag,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::MissingFlag,
+                    format!("Flag `{}` never existed within the context", name),
+                ));
+            }
+        };
+        if !matches!(flag.r#type, Type::Path | Type::Str) {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "Only `Type::Path` or `Type::Str` flags can give a config file, found `{}`",
+                    name
+                ),
+            ));
+        }
+        self.config_flag = Some(flag.name);
+        Ok(())
+    }
+
+    /// Adds a location searched for the configuration file when none is given through
+    /// the config flag, the first existing file among the locations is read
+    pub fn add_config_path<P: Into<PathBuf>>(&mut self, path: P) {
+        self.config_paths.push(path.into());
+    }
+
     pub fn add_args(&mut self, args: &[Arg]) -> Result<(), Error> {
         for arg in args {
             self.add_arg(arg.clone())?;
@@ -118,6 +456,26 @@ impl Context {
         None
     }
 
+    /// Looks for the flag whose short form is `short`
+    pub fn get_flag_by_short(&self, short: char) -> Option<Flag> {
+        for flag in self.flags.iter() {
+            if flag.short == Some(short) {
+                return Some(flag.clone());
+            }
+        }
+        None
+    }
+
+    /// Looks for the negatable flag whose `--no-` counterpart is `name`
+    pub fn get_flag_by_negation(&self, name: &str) -> Option<Flag> {
+        for flag in self.flags.iter() {
+            if flag.get_negated_name().as_deref() == Some(name) {
+                return Some(flag.clone());
+            }
+        }
+        None
+    }
+
     pub fn add_flag(&mut self, flag: Flag) -> Result<(), Error> {
         if flag.name == "--" {
             return Err(Error::new(
@@ -154,6 +512,267 @@ impl Context {
         };
         Ok(self.flags.remove(index))
     }
+
+    pub fn add_subcommands(&mut self, subcommands: &[Subcommand]) -> Result<(), Error> {
+        for subcommand in subcommands {
+            self.add_subcommand(subcommand.clone())?;
+        }
+        Ok(())
+    }
+
+    pub fn contains_subcommand(&self, name: &str) -> bool {
+        for subcommand in self.subcommands.iter() {
+            if subcommand.name == name {
+                return true;
+            }
+        }
+        false
+    }
+
+    pub fn get_subcommand(&self, name: &str) -> Option<Subcommand> {
+        for subcommand in self.subcommands.iter() {
+            if subcommand.name == name {
+                return Some(subcommand.clone());
+            }
+        }
+        None
+    }
+
+    pub fn add_subcommand(&mut self, subcommand: Subcommand) -> Result<(), Error> {
+        if subcommand.name.is_empty() || subcommand.name.starts_with('-') {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("`{}` is an invalid name for a Subcommand", &subcommand.name),
+            ));
+        }
+        if self.contains_subcommand(&subcommand.name) {
+            return Err(Error::new(
+                ErrorKind::DuplicateSubcommand,
+                format!("Found a duplicate subcommand for `{}`", &subcommand.name),
+            ));
+        }
+        self.subcommands.push(subcommand);
+        Ok(())
+    }
+
+    pub fn remove_subcommand(&mut self, name: &str) -> Result<Subcommand, Error> {
+        if !self.contains_subcommand(name) {
+            return Err(Error::new(
+                ErrorKind::MissingSubcommand,
+                format!("Subcommand `{}` never existed within the context", name),
+            ));
+        }
+        let index: usize = {
+            let mut bind = 0;
+            for (i, sub) in self.subcommands.iter().enumerate() {
+                if sub.name == name {
+                    bind = i;
+                    break;
+                }
+            }
+            bind
+        };
+        Ok(self.subcommands.remove(index))
+    }
+
+    /// Checks that every relation, e.g. `requires`, `required_if` or group members,
+    /// refers to a flag or argument defined within the context, subcommands included.
+    ///
+    /// Relations may refer to flags and arguments added later on, thus they are only
+    /// checked once the context is complete, which parsing does on its own
+    /// ```rust
+    /// use parsin::{try_parse_from, Arg, Context, Flag, Type};
+    /// use parsin::errors::ErrorKind;
+    ///
+    /// # fn main() {
+    /// let ctx = Context::from((
+    ///     &[] as &[Arg; 0],
+    ///     &[Flag::from(("--key-file", Type::Path, "Private key", false, None)).requires("--tls")],
+    /// ));
+    /// assert_eq!(ctx.validate().unwrap_err().kind(), ErrorKind::UndefinedName);
+    /// let error = try_parse_from(&ctx, [] as [&str; 0]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::UndefinedName);
+    /// # }
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        for arg in self.args.iter() {
+            check_bounds(&arg.name, &arg.r#type, arg.min_value, arg.max_value)?;
+        }
+        if let Some(arg) = self.args.iter().filter(|a| a.variadic).nth(1) {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "Only one variadic Arg is allowed within a Context, found `{}`",
+                    &arg.name
+                ),
+            ));
+        }
+        let mut shorts: Vec<char> = Vec::new();
+        for flag in self.flags.iter() {
+            check_bounds(&flag.name, &flag.r#type, flag.min_value, flag.max_value)?;
+            if let Some(short) = flag.short {
+                if short == '-' {
+                    return Err(Error::new(
+                        ErrorKind::Other,
+                        "`-` is an invalid short form for a Flag".to_string(),
+                    ));
+                }
+                if shorts.contains(&short) {
+                    return Err(Error::new(
+                        ErrorKind::DuplicateFlag,
+                        format!("Found a duplicate flag for `-{}`", short),
+                    ));
+                }
+                shorts.push(short);
+            }
+            if let Some(negated) = flag.get_negated_name() {
+                if !matches!(flag.r#type, Type::Bool) {
+                    return Err(Error::new(
+                        ErrorKind::Other,
+                        format!(
+                            "Only `Type::Bool` flags can be negatable, found `{}`",
+                            &flag.name
+                        ),
+                    ));
+                }
+                if self.contains_flag(&negated) {
+                    return Err(Error::new(
+                        ErrorKind::DuplicateFlag,
+                        format!("Found a duplicate flag for `{}`", negated),
+                    ));
+                }
+            }
+        }
+        let mut relations: Vec<(&String, &String)> = Vec::new();
+        for arg in self.args.iter() {
+            let names = arg
+                .requires
+                .iter()
+                .chain(arg.conflicts_with.iter())
+                .chain(arg.required_if.iter())
+                .chain(arg.required_unless.iter());
+            for name in names {
+                relations.push((&arg.name, name));
+            }
+        }
+        for flag in self.flags.iter() {
+            let names = flag
+                .requires
+                .iter()
+                .chain(flag.conflicts_with.iter())
+                .chain(flag.required_if.iter())
+                .chain(flag.required_unless.iter());
+            for name in names {
+                relations.push((&flag.name, name));
+            }
+        }
+        for group in self.groups.iter() {
+            for name in group.members.iter() {
+                relations.push((&group.name, name));
+            }
+        }
+        for (owner, name) in relations {
+            if !self.contains_flag(name) && !self.contains_arg(name) {
+                return Err(Error::new(
+                    ErrorKind::UndefinedName,
+                    format!(
+                        "`{}` refers to `{}`, which is neither a flag nor an argument",
+                        owner, name
+                    ),
+                ));
+            }
+        }
+        for subcommand in self.subcommands.iter() {
+            subcommand.context.validate()?;
+        }
+        Ok(())
+    }
+
+    pub fn add_groups(&mut self, groups: &[Group]) -> Result<(), Error> {
+        for group in groups {
+            self.add_group(group.clone())?;
+        }
+        Ok(())
+    }
+
+    pub fn contains_group(&self, name: &str) -> bool {
+        for group in self.groups.iter() {
+            if group.name == name {
+                return true;
+            }
+        }
+        false
+    }
+
+    pub fn get_group(&self, name: &str) -> Option<Group> {
+        for group in self.groups.iter() {
+            if group.name == name {
+                return Some(group.clone());
+            }
+        }
+        None
+    }
+
+    /// Adds a group, whose members may be flags or arguments defined later on,
+    /// see [`Context::validate`]
+    pub fn add_group(&mut self, group: Group) -> Result<(), Error> {
+        if self.contains_group(&group.name) {
+            return Err(Error::new(
+                ErrorKind::DuplicateGroup,
+                format!("Found a duplicate group for `{}`", &group.name),
+            ));
+        }
+        self.groups.push(group);
+        Ok(())
+    }
+
+    pub fn remove_group(&mut self, name: &str) -> Result<Group, Error> {
+        if !self.contains_group(name) {
+            return Err(Error::new(
+                ErrorKind::MissingGroup,
+                format!("Group `{}` never existed within the context", name),
+            ));
+        }
+        let index: usize = {
+            let mut bind = 0;
+            for (i, group) in self.groups.iter().enumerate() {
+                if group.name == name {
+                    bind = i;
+                    break;
+                }
+            }
+            bind
+        };
+        Ok(self.groups.remove(index))
+    }
+}
+
+/// Checks that the bounds of a flag or argument, if any, apply to a `Type::Int`
+/// and leave room for at least one value
+fn check_bounds(
+    name: &str,
+    r#type: &Type,
+    min: Option<i32>,
+    max: Option<i32>,
+) -> Result<(), Error> {
+    if (min.is_some() || max.is_some()) && !matches!(r#type, Type::Int) {
+        return Err(Error::new(
+            ErrorKind::Other,
+            format!("Only `Type::Int` can be bounded, found `{}`", name),
+        ));
+    }
+    if let (Some(min), Some(max)) = (min, max) {
+        if min > max {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "`{}` is bounded by a minimum of {} above its maximum of {}",
+                    name, min, max
+                ),
+            ));
+        }
+    }
+    Ok(())
 }
 
 impl Default for Context {
@@ -176,7 +795,19 @@ impl<const A: usize, const B: usize>
     ) -> Self {
         let args: Vec<Arg> = _tuple.0.iter().map(Arg::from).collect();
         let flags: Vec<Flag> = _tuple.1.iter().map(Flag::from).collect();
-        Self { args, flags }
+        Self {
+            args,
+            flags,
+            subcommands: Vec::new(),
+            groups: Vec::new(),
+            capture_trailing: false,
+            allow_extra_arguments: false,
+            allow_unknown_flags: false,
+            allow_prefix_matching: false,
+            expand_response_files: false,
+            config_flag: None,
+            config_paths: Vec::new(),
+        }
     }
 }
 
@@ -194,7 +825,19 @@ impl<const A: usize, const B: usize>
     ) -> Self {
         let args: Vec<Arg> = _tuple.0.iter().map(Arg::from).collect();
         let flags: Vec<Flag> = _tuple.1.iter().map(Flag::from).collect();
-        Self { args, flags }
+        Self {
+            args,
+            flags,
+            subcommands: Vec::new(),
+            groups: Vec::new(),
+            capture_trailing: false,
+            allow_extra_arguments: false,
+            allow_unknown_flags: false,
+            allow_prefix_matching: false,
+            expand_response_files: false,
+            config_flag: None,
+            config_paths: Vec::new(),
+        }
     }
 }
 
@@ -203,6 +846,15 @@ impl<const A: usize, const B: usize> From<&(&[Arg; A], &[Flag; B])> for Context
         Self {
             args: _tuple.0.to_vec(),
             flags: _tuple.1.to_vec(),
+            subcommands: Vec::new(),
+            groups: Vec::new(),
+            capture_trailing: false,
+            allow_extra_arguments: false,
+            allow_unknown_flags: false,
+            allow_prefix_matching: false,
+            expand_response_files: false,
+            config_flag: None,
+            config_paths: Vec::new(),
         }
     }
 }
@@ -212,6 +864,15 @@ impl<const A: usize, const B: usize> From<(&[Arg; A], &[Flag; B])> for Context {
         Self {
             args: _tuple.0.to_vec(),
             flags: _tuple.1.to_vec(),
+            subcommands: Vec::new(),
+            groups: Vec::new(),
+            capture_trailing: false,
+            allow_extra_arguments: false,
+            allow_unknown_flags: false,
+            allow_prefix_matching: false,
+            expand_response_files: false,
+            config_flag: None,
+            config_paths: Vec::new(),
         }
     }
 }
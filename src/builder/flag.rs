@@ -1,3 +1,4 @@
+use crate::builder::validator::Validator;
 use crate::Type;
 
 /// Defines a Flag within [Context][struct@crate::Context]
@@ -46,6 +47,74 @@ use crate::Type;
 /// );
 /// # }
 /// ```
+/// A flag can also be given a short form, which can be clustered with
+/// other short flags on the command line, e.g. `-xvf file`.
+/// ```rust
+/// use parsin::{try_parse_from, Context, Type, Flag};
+/// use parsin::errors::ErrorKind;
+///
+/// # fn main() {
+/// let ctx = Context::from((
+///     &[],
+///     &[
+///         Flag::from(("--extract", Type::Bool, "Extracts files", false, None)).short('x'),
+///         Flag::from(("--verbose", Type::Bool, "Displays more information", false, None))
+///             .short('v'),
+///         Flag::from(("--file", Type::Path, "Archive to read", false, None)).short('f'),
+///     ],
+/// ));
+/// let parsed = try_parse_from(&ctx, ["-xvf", "file"]).unwrap();
+/// assert!(*parsed.flags["--extract"].unwrap_bool());
+/// assert!(*parsed.flags["--verbose"].unwrap_bool());
+/// assert_eq!(parsed.flags["--file"].unwrap_path().to_str(), Some("file"));
+///
+/// // the value may be attached to the short form
+/// let parsed = try_parse_from(&ctx, ["-ffile"]).unwrap();
+/// assert_eq!(parsed.flags["--file"].unwrap_path().to_str(), Some("file"));
+///
+/// let error = try_parse_from(&ctx, ["-xzv"]).unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::UnknownFlag);
+///
+/// let help = try_parse_from(&ctx, ["--help"]).unwrap_err().diagnosis();
+/// assert!(help.contains("-v, --verbose   Displays more information"));
+///
+/// // short forms must be unique within a context
+/// let ctx = Context::from((
+///     &[],
+///     &[
+///         Flag::from(("--verbose", Type::Bool, "Displays more information", false, None))
+///             .short('v'),
+///         Flag::from(("--version", Type::Bool, "Displays the version", false, None)).short('v'),
+///     ],
+/// ));
+/// assert_eq!(ctx.validate().unwrap_err().kind(), ErrorKind::DuplicateFlag);
+/// # }
+/// ```
+/// A value may also be attached with `=`, splitting on the first one, e.g. `--file=a=b`.
+/// ```rust
+/// use parsin::{try_parse_from, Context, Type, Flag};
+/// use parsin::errors::ErrorKind;
+///
+/// # fn main() {
+/// let ctx = Context::from((
+///     &[],
+///     &[
+///         Flag::from(("--verbose", Type::Bool, "Displays more information", false, None)),
+///         Flag::from(("--file", Type::Str, "File to read", false, None)).short('f'),
+///     ],
+/// ));
+/// let parsed = try_parse_from(&ctx, ["--file=a=b"]).unwrap();
+/// assert_eq!(parsed.flags["--file"].unwrap_str(), "a=b");
+/// let parsed = try_parse_from(&ctx, ["-f=x"]).unwrap();
+/// assert_eq!(parsed.flags["--file"].unwrap_str(), "x");
+/// let parsed = try_parse_from(&ctx, ["--file="]).unwrap();
+/// assert_eq!(parsed.flags["--file"].unwrap_str(), "");
+///
+/// // flags taking no value reject one
+/// let error = try_parse_from(&ctx, ["--verbose=1"]).unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::UnexpectedValue);
+/// # }
+/// ```
 #[derive(Debug, Clone)]
 pub struct Flag {
     /// The flag itself used to indicate a flag
@@ -58,6 +127,40 @@ pub struct Flag {
     pub(crate) is_mandatory: bool,
     /// Introduce a string default value, that'll get parsed later during execution
     pub(crate) default: Option<String>,
+    /// Optional single character alias, used as `-c`
+    pub(crate) short: Option<char>,
+    /// Signals if the flag may be given more than once
+    pub(crate) multiple: bool,
+    /// Least amount of times a repeatable flag must be given
+    pub(crate) min_occurrences: Option<usize>,
+    /// Most amount of times a repeatable flag may be given
+    pub(crate) max_occurrences: Option<usize>,
+    /// Upper cap on the count of a `Type::Count` flag
+    pub(crate) max_count: Option<i32>,
+    /// Signals if a `--no-` counterpart is generated for a `Type::Bool` flag
+    pub(crate) negatable: bool,
+    /// Environment variable consulted when the flag is not given
+    pub(crate) env: Option<String>,
+    /// Names of the flags and arguments that must be given along with this one
+    pub(crate) requires: Vec<String>,
+    /// Names of the flags and arguments that cannot be given along with this one
+    pub(crate) conflicts_with: Vec<String>,
+    /// Names of the flags and arguments making this one mandatory when given
+    pub(crate) required_if: Vec<String>,
+    /// Names of the flags and arguments making this one mandatory when none is given
+    pub(crate) required_unless: Vec<String>,
+    /// Closures checking every value given to the flag
+    pub(crate) validators: Vec<Validator>,
+    /// The only values the flag accepts, when not empty
+    pub(crate) possible_values: Vec<String>,
+    /// Signals if the possible values are matched regardless of case
+    pub(crate) ignore_case: bool,
+    /// Least value a `Type::Int` flag accepts
+    pub(crate) min_value: Option<i32>,
+    /// Greatest value a `Type::Int` flag accepts
+    pub(crate) max_value: Option<i32>,
+    /// Signals if a `Type::Float` flag accepts NaN and infinite numbers
+    pub(crate) allow_non_finite: bool,
 }
 
 impl Flag {
@@ -75,9 +178,381 @@ impl Flag {
             help,
             is_mandatory,
             default,
+            short: None,
+            multiple: false,
+            min_occurrences: None,
+            max_occurrences: None,
+            max_count: None,
+            negatable: false,
+            env: None,
+            requires: Vec::new(),
+            conflicts_with: Vec::new(),
+            required_if: Vec::new(),
+            required_unless: Vec::new(),
+            validators: Vec::new(),
+            possible_values: Vec::new(),
+            ignore_case: false,
+            min_value: None,
+            max_value: None,
+            allow_non_finite: false,
         }
     }
 
+    /// Gives the flag a short form, e.g. `'v'` for `-v`
+    pub fn short(mut self, short: char) -> Self {
+        self.short = Some(short);
+        self
+    }
+
+    /// Allows the flag to be given more than once, collecting every value given in order
+    /// into a [`Value::List`](enum@crate::parser::Value), e.g. `--include a --include b`
+    /// ```rust
+    /// use parsin::{try_parse_from, Context, Type, Flag};
+    /// use parsin::errors::ErrorKind;
+    /// use parsin::parser::Value;
+    ///
+    /// # fn main() {
+    /// let flag = Flag::from(
+    ///     ("--include", Type::Str, "Directory to include", false, None)
+    /// ).repeatable().min_occurrences(1).max_occurrences(3);
+    /// let ctx = Context::from((&[], &[flag.clone()]));
+    /// let parsed = try_parse_from(&ctx, ["--include", "a", "--include", "b"]).unwrap();
+    /// let includes: Vec<&String> = parsed.flags["--include"]
+    ///     .unwrap_list()
+    ///     .iter()
+    ///     .map(Value::unwrap_str)
+    ///     .collect();
+    /// assert_eq!(includes, ["a", "b"]);
+    ///
+    /// let error = try_parse_from(&ctx, [] as [&str; 0]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::TooFewOccurrences);
+    /// let error = try_parse_from(&ctx, ["--include", "a"].repeat(4)).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::TooManyOccurrences);
+    ///
+    /// // a repeatable flag that was never given holds an empty list
+    /// let ctx = Context::from((&[], &[flag.min_occurrences(0)]));
+    /// let parsed = try_parse_from(&ctx, [] as [&str; 0]).unwrap();
+    /// assert!(parsed.flags["--include"].unwrap_list().is_empty());
+    /// # }
+    /// ```
+    pub fn repeatable(mut self) -> Self {
+        self.multiple = true;
+        self
+    }
+
+    /// Least amount of times the flag must be given, makes the flag repeatable.
+    ///
+    /// A value taken from the environment variable of the flag counts as one occurrence,
+    /// each value given within the configuration file counts as one as well
+    /// ```rust
+    /// use parsin::{try_parse_from, Context, Flag, Type};
+    ///
+    /// # fn main() {
+    /// std::env::set_var("PARSIN_DOC_INCLUDE", "src");
+    /// let flag = Flag::from(("--include", Type::Str, "Directory to include", false, None))
+    ///     .min_occurrences(1)
+    ///     .env("PARSIN_DOC_INCLUDE");
+    /// let ctx = Context::from((&[], &[flag]));
+    /// let parsed = try_parse_from(&ctx, [] as [&str; 0]).unwrap();
+    /// assert_eq!(parsed.flags["--include"].unwrap_list().len(), 1);
+    /// # }
+    /// ```
+    pub fn min_occurrences(mut self, min: usize) -> Self {
+        self.multiple = true;
+        self.min_occurrences = Some(min);
+        self
+    }
+
+    /// Most amount of times the flag may be given, makes the flag repeatable
+    pub fn max_occurrences(mut self, max: usize) -> Self {
+        self.multiple = true;
+        self.max_occurrences = Some(max);
+        self
+    }
+
+    /// Caps the count of a `Type::Count` flag, which yields how many times it was given,
+    /// e.g. `-vvv` yields `3`. Any further occurrence is ignored
+    /// ```rust
+    /// use parsin::{parse_from, Context, Type, Flag};
+    ///
+    /// # fn main() {
+    /// let flag = Flag::from(
+    ///     ("--verbose", Type::Count, "Verbosity level", false, None)
+    /// ).short('v').max_count(2);
+    /// let ctx = Context::from((&[], &[flag]));
+    /// let parsed = parse_from(&ctx, ["-vvv"]);
+    /// assert_eq!(*parsed.flags["--verbose"].unwrap_int(), 2);
+    /// # }
+    /// ```
+    pub fn max_count(mut self, max: i32) -> Self {
+        self.max_count = Some(max);
+        self
+    }
+
+    /// Generates a `--no-` counterpart for a `Type::Bool` flag, e.g. `--no-color`
+    /// for `--color`, which sets the flag to `false`. The last one given wins
+    /// ```rust
+    /// use parsin::{parse_from, try_parse_from, Context, Type, Flag};
+    /// use parsin::errors::ErrorKind;
+    ///
+    /// # fn main() {
+    /// let flag = Flag::from(
+    ///     ("--color", Type::Bool, "Colors the output", false, Some("true"))
+    /// ).negatable();
+    /// let ctx = Context::from((&[], &[flag.clone()]));
+    /// let parsed = parse_from(&ctx, ["--color", "--no-color"]);
+    /// assert!(!*parsed.flags["--color"].unwrap_bool());
+    ///
+    /// let help = try_parse_from(&ctx, ["--help"]).unwrap_err().diagnosis();
+    /// assert!(help.contains("--[no-]color   Colors the output"));
+    ///
+    /// // only `Type::Bool` flags can be negatable
+    /// let ctx = Context::from((
+    ///     &[],
+    ///     &[Flag::from(("--name", Type::Str, "Your name", false, None)).negatable()],
+    /// ));
+    /// assert_eq!(ctx.validate().unwrap_err().kind(), ErrorKind::Other);
+    ///
+    /// // the `--no-` counterpart must not clash with another flag
+    /// let ctx = Context::from((
+    ///     &[],
+    ///     &[flag, Flag::from(("--no-color", Type::Bool, "Strips colors", false, None))],
+    /// ));
+    /// assert_eq!(ctx.validate().unwrap_err().kind(), ErrorKind::DuplicateFlag);
+    /// # }
+    /// ```
+    pub fn negatable(mut self) -> Self {
+        self.negatable = true;
+        self
+    }
+
+    /// Names an environment variable whose value is taken when the flag is not given,
+    /// before falling back onto the default value, which also satisfies a mandatory flag
+    /// ```rust
+    /// use parsin::{parse_from, try_parse_from, Context, Type, Flag};
+    ///
+    /// # fn main() {
+    /// std::env::set_var("PARSIN_DOC_PORT", "8080");
+    /// let flag = Flag::from(
+    ///     ("--port", Type::Int, "Port to listen on", true, None)
+    /// ).env("PARSIN_DOC_PORT");
+    /// let ctx = Context::from((&[], &[flag]));
+    /// let parsed = parse_from(&ctx, [] as [&str; 0]);
+    /// assert_eq!(*parsed.flags["--port"].unwrap_int(), 8080);
+    ///
+    /// let help = try_parse_from(&ctx, ["--help"]).unwrap_err().diagnosis();
+    /// assert!(help.contains("--port   Port to listen on [env: PARSIN_DOC_PORT]"));
+    /// # }
+    /// ```
+    pub fn env(mut self, var: &str) -> Self {
+        self.env = Some(var.to_string());
+        self
+    }
+
+    /// Requires the flag or argument named `name` to be given whenever this flag is given
+    /// ```rust
+    /// use parsin::{try_parse_from, Context, Type, Flag};
+    /// use parsin::errors::ErrorKind;
+    ///
+    /// # fn main() {
+    /// let ctx = Context::from((
+    ///     &[],
+    ///     &[
+    ///         Flag::from(("--tls", Type::Bool, "Enables TLS", false, None)),
+    ///         Flag::from(("--key-file", Type::Path, "Private key", false, None)).requires("--tls"),
+    ///     ],
+    /// ));
+    /// let error = try_parse_from(&ctx, ["--key-file", "key.pem"]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::MissingFlag);
+    /// # }
+    /// ```
+    /// Either may be given through its environment variable or the configuration file
+    /// ```rust
+    /// use parsin::{try_parse_from, Context, Flag, Type};
+    ///
+    /// # fn main() {
+    /// std::env::set_var("PARSIN_DOC_TLS", "true");
+    /// let ctx = Context::from((
+    ///     &[],
+    ///     &[
+    ///         Flag::from(("--tls", Type::Bool, "Enables TLS", false, None)).env("PARSIN_DOC_TLS"),
+    ///         Flag::from(("--key-file", Type::Path, "Private key", false, None)).requires("--tls"),
+    ///     ],
+    /// ));
+    /// assert!(try_parse_from(&ctx, ["--key-file", "key.pem"]).is_ok());
+    /// # }
+    /// ```
+    pub fn requires(mut self, name: &str) -> Self {
+        self.requires.push(name.to_string());
+        self
+    }
+
+    /// Forbids the flag or argument named `name` from being given along with this flag
+    /// ```rust
+    /// use parsin::{try_parse_from, Context, Type, Flag};
+    /// use parsin::errors::ErrorKind;
+    ///
+    /// # fn main() {
+    /// let ctx = Context::from((
+    ///     &[],
+    ///     &[
+    ///         Flag::from(("--force", Type::Bool, "Overwrites files", false, None)),
+    ///         Flag::from(("--dry-run", Type::Bool, "Changes nothing", false, None))
+    ///             .conflicts_with("--force"),
+    ///     ],
+    /// ));
+    /// let error = try_parse_from(&ctx, ["--force", "--dry-run"]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::ArgumentConflict);
+    /// # }
+    /// ```
+    pub fn conflicts_with(mut self, name: &str) -> Self {
+        self.conflicts_with.push(name.to_string());
+        self
+    }
+
+    /// Makes the flag mandatory whenever the flag or argument named `name` is given
+    /// ```rust
+    /// use parsin::{try_parse_from, Context, Flag, Type};
+    /// use parsin::errors::ErrorKind;
+    ///
+    /// # fn main() {
+    /// let ctx = Context::from((
+    ///     &[],
+    ///     &[
+    ///         Flag::from(("--user", Type::Str, "User to log in as", false, None)),
+    ///         Flag::from(("--password", Type::Str, "Password of the user", false, None))
+    ///             .required_if("--user"),
+    ///     ],
+    /// ));
+    /// assert!(try_parse_from(&ctx, [] as [&str; 0]).is_ok());
+    /// let error = try_parse_from(&ctx, ["--user", "admin"]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::MissingFlag);
+    /// assert_eq!(
+    ///     error.diagnosis(),
+    ///     "Missing flag `--password`, which is required because `--user` was given"
+    /// );
+    /// # }
+    /// ```
+    pub fn required_if(mut self, name: &str) -> Self {
+        self.required_if.push(name.to_string());
+        self
+    }
+
+    /// Makes the flag mandatory unless the flag or argument named `name` is given,
+    /// when called more than once, any of them being given is enough
+    pub fn required_unless(mut self, name: &str) -> Self {
+        self.required_unless.push(name.to_string());
+        self
+    }
+
+    /// Attaches a closure checking every value given to the flag, which returns
+    /// why a value was rejected. Validators run in the order they were attached
+    /// and check the default value as well
+    pub fn validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.validators.push(Validator::new(validator));
+        self
+    }
+
+    /// Restricts the values the flag accepts, e.g. `&["json", "yaml", "text"]`,
+    /// which the default value must be one of
+    /// ```rust
+    /// use parsin::{try_parse_from, Context, Type, Flag};
+    ///
+    /// # fn main() {
+    /// let flag = Flag::from(
+    ///     ("--format", Type::Str, "Output format", false, Some("text"))
+    /// ).possible_values(&["json", "yaml", "text"]).ignore_case();
+    /// let ctx = Context::from((&[], &[flag]));
+    /// let parsed = try_parse_from(&ctx, ["--format", "JSON"]).unwrap();
+    /// assert_eq!(parsed.flags["--format"].unwrap_str(), "json");
+    /// assert!(try_parse_from(&ctx, ["--format", "xml"]).is_err());
+    ///
+    /// let help = try_parse_from(&ctx, ["--help"]).unwrap_err().diagnosis();
+    /// assert!(help.contains("Output format [possible values: json, yaml, text]"));
+    ///
+    /// // the default value must be one of the possible values as well
+    /// let flag = Flag::from(
+    ///     ("--format", Type::Str, "Output format", false, Some("xml"))
+    /// ).possible_values(&["json", "yaml", "text"]);
+    /// let ctx = Context::from((&[], &[flag]));
+    /// assert!(try_parse_from(&ctx, [] as [&str; 0]).is_err());
+    /// # }
+    /// ```
+    pub fn possible_values(mut self, values: &[&str]) -> Self {
+        self.possible_values = values.iter().map(|v| v.to_string()).collect();
+        self
+    }
+
+    /// Matches the possible values regardless of case, the value is
+    /// then spelled the way it was defined, e.g. `JSON` yields `json`
+    pub fn ignore_case(mut self) -> Self {
+        self.ignore_case = true;
+        self
+    }
+
+    /// Least value a `Type::Int` flag accepts, inclusive, any value out of range is rejected
+    /// ```rust
+    /// use parsin::{try_parse_from, Context, Type, Flag};
+    /// use parsin::errors::ErrorKind;
+    ///
+    /// # fn main() {
+    /// let flag = Flag::from(
+    ///     ("--port", Type::Int, "Port to listen on", false, Some("8080"))
+    /// ).min_value(1).max_value(65535);
+    /// let ctx = Context::from((&[], &[flag]));
+    /// let error = try_parse_from(&ctx, ["--port", "99999"]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::OutOfRange);
+    ///
+    /// let help = try_parse_from(&ctx, ["--help"]).unwrap_err().diagnosis();
+    /// assert!(help.contains("Port to listen on [range: 1..=65535]"));
+    ///
+    /// // defaults are held to the same bounds, which must be consistent
+    /// let flag = Flag::from(("--port", Type::Int, "Port to listen on", false, Some("0")));
+    /// let ctx = Context::from((&[], &[flag.clone().min_value(1)]));
+    /// let error = try_parse_from(&ctx, [] as [&str; 0]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::OutOfRange);
+    /// let ctx = Context::from((&[], &[flag.min_value(10).max_value(1)]));
+    /// assert!(ctx.validate().is_err());
+    /// # }
+    /// ```
+    pub fn min_value(mut self, min: i32) -> Self {
+        self.min_value = Some(min);
+        self
+    }
+
+    /// Greatest value a `Type::Int` flag accepts, inclusive
+    pub fn max_value(mut self, max: i32) -> Self {
+        self.max_value = Some(max);
+        self
+    }
+
+    /// Allows a `Type::Float` flag to take NaN and infinite numbers, e.g. `inf`,
+    /// which are rejected otherwise
+    /// ```rust
+    /// use parsin::{try_parse_from, Context, Type, Flag};
+    ///
+    /// # fn main() {
+    /// let ctx = Context::from((
+    ///     &[],
+    ///     &[
+    ///         Flag::from(("--sample-rate", Type::Float, "Ratio to sample", false, Some("1.0"))),
+    ///         Flag::from(("--timeout", Type::Float, "Seconds to wait", false, None)).allow_non_finite(),
+    ///     ],
+    /// ));
+    /// let parsed = try_parse_from(&ctx, ["--sample-rate", "0.25", "--timeout", "inf"]).unwrap();
+    /// assert_eq!(*parsed.flags["--sample-rate"].unwrap_float(), 0.25);
+    /// assert!(parsed.flags["--timeout"].unwrap_float().is_infinite());
+    /// assert!(try_parse_from(&ctx, ["--sample-rate", "NaN"]).is_err());
+    /// # }
+    /// ```
+    pub fn allow_non_finite(mut self) -> Self {
+        self.allow_non_finite = true;
+        self
+    }
+
     pub fn get_name(&self) -> String {
         self.name.clone()
     }
@@ -97,6 +572,80 @@ impl Flag {
     pub fn get_default(&self) -> Option<String> {
         self.default.clone()
     }
+
+    pub fn get_short(&self) -> Option<char> {
+        self.short
+    }
+
+    pub fn is_repeatable(&self) -> bool {
+        self.multiple
+    }
+
+    pub fn get_min_occurrences(&self) -> Option<usize> {
+        self.min_occurrences
+    }
+
+    pub fn get_max_occurrences(&self) -> Option<usize> {
+        self.max_occurrences
+    }
+
+    pub fn get_max_count(&self) -> Option<i32> {
+        self.max_count
+    }
+
+    pub fn is_negatable(&self) -> bool {
+        self.negatable
+    }
+
+    pub fn get_env(&self) -> Option<String> {
+        self.env.clone()
+    }
+
+    pub fn get_requires(&self) -> Vec<String> {
+        self.requires.clone()
+    }
+
+    pub fn get_conflicts_with(&self) -> Vec<String> {
+        self.conflicts_with.clone()
+    }
+
+    pub fn get_required_if(&self) -> Vec<String> {
+        self.required_if.clone()
+    }
+
+    pub fn get_required_unless(&self) -> Vec<String> {
+        self.required_unless.clone()
+    }
+
+    pub fn get_possible_values(&self) -> Vec<String> {
+        self.possible_values.clone()
+    }
+
+    pub fn is_ignoring_case(&self) -> bool {
+        self.ignore_case
+    }
+
+    pub fn get_min_value(&self) -> Option<i32> {
+        self.min_value
+    }
+
+    pub fn get_max_value(&self) -> Option<i32> {
+        self.max_value
+    }
+
+    pub fn allows_non_finite(&self) -> bool {
+        self.allow_non_finite
+    }
+
+    /// The name of the `--no-` counterpart, if the flag is negatable
+    pub fn get_negated_name(&self) -> Option<String> {
+        if !self.negatable {
+            return None;
+        }
+        let bare = self.name.trim_start_matches('-');
+        let dashes = &self.name[..self.name.len() - bare.len()];
+        Some(format!("{}no-{}", dashes, bare))
+    }
 }
 
 impl From<(String, Type, String, bool, Option<String>)> for Flag {
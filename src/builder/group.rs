@@ -0,0 +1,109 @@
+/// Defines a Group of mutually exclusive flags and arguments within [Context](struct@crate::Context)
+///
+/// At most one member of a group may be given, and exactly one when the group is required.
+///
+/// ### Getting Started
+/// Initializing [Group]
+/// ```rust
+/// use parsin::Group;
+///
+/// # fn main() {
+/// let group = Group::new(
+///     String::from("format"),
+///     vec![String::from("--json"), String::from("--yaml")]
+/// );
+/// # }
+/// ```
+/// Alternatively, a simpler initialization would be utilizing the [`From`] trait.
+/// ```rust
+/// use parsin::{try_parse_from, Context, Group, Type};
+/// use parsin::errors::ErrorKind;
+///
+/// # fn main() {
+/// let mut ctx = Context::from((
+///     &[],
+///     &[
+///         ("--json", Type::Bool, "Outputs JSON", false, None),
+///         ("--yaml", Type::Bool, "Outputs YAML", false, None),
+///     ],
+/// ));
+/// ctx.add_group(Group::from(("format", &["--json", "--yaml"])).required()).unwrap();
+///
+/// let error = try_parse_from(&ctx, ["--json", "--yaml"]).unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::ArgumentConflict);
+/// let error = try_parse_from(&ctx, [] as [&str; 0]).unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::MissingGroupMember);
+/// assert!(try_parse_from(&ctx, ["--yaml"]).is_ok());
+/// # }
+/// ```
+/// Arguments may belong to a group as well, and are then displayed within the group.
+/// ```rust
+/// use parsin::{try_parse_from, Arg, Context, Flag, Group, Type};
+///
+/// # fn main() {
+/// let mut ctx = Context::from((
+///     &[Arg::from(("file", Type::Path, "File to read", false, None))],
+///     &[Flag::from(("--stdin", Type::Bool, "Reads the standard input", false, None))],
+/// ));
+/// ctx.add_group(Group::from(("input", &["file", "--stdin"])).required()).unwrap();
+///
+/// let help = try_parse_from(&ctx, ["--help"]).unwrap_err().diagnosis();
+/// assert!(help.contains("[OPTIONS] (<FILE> | --stdin)\n"));
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Group {
+    /// The name of the group
+    pub(crate) name: String,
+    /// Names of the flags and arguments within the group
+    pub(crate) members: Vec<String>,
+    /// Signals if exactly one member must be given
+    pub(crate) required: bool,
+}
+
+impl Group {
+    /// Initializes an instance of Group
+    pub fn new(name: String, members: Vec<String>) -> Self {
+        Self {
+            name,
+            members,
+            required: false,
+        }
+    }
+
+    /// Requires exactly one member of the group to be given
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_members(&self) -> Vec<String> {
+        self.members.clone()
+    }
+
+    pub fn is_required(&self) -> bool {
+        self.required
+    }
+}
+
+impl<const N: usize> From<(&str, &[&str; N])> for Group {
+    fn from(_tuple: (&str, &[&str; N])) -> Self {
+        Self::new(
+            _tuple.0.to_string(),
+            _tuple.1.iter().map(|m| m.to_string()).collect(),
+        )
+    }
+}
+
+impl<const N: usize> From<&(&str, &[&str; N])> for Group {
+    fn from(_tuple: &(&str, &[&str; N])) -> Self {
+        Self::new(
+            _tuple.0.to_string(),
+            _tuple.1.iter().map(|m| m.to_string()).collect(),
+        )
+    }
+}
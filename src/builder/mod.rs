@@ -1,3 +1,6 @@
 pub mod arg;
 pub mod context;
 pub mod flag;
+pub mod group;
+pub mod subcommand;
+pub mod validator;
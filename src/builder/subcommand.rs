@@ -0,0 +1,120 @@
+use crate::Context;
+
+/// Defines a Subcommand within [Context](struct@crate::Context)
+///
+/// A subcommand owns its own [Context](struct@crate::Context), which
+/// may define further subcommands, e.g. `tool build --release`.
+///
+/// ### Getting Started
+/// Initializing [Subcommand]
+/// ```rust
+/// use parsin::{Context, Subcommand, Type};
+///
+/// # fn main() {
+/// let build = Context::from((
+///     &[],
+///     &[("--release", Type::Bool, "Builds with optimizations", false, None)],
+/// ));
+/// let subcommand = Subcommand::new(
+///     String::from("build"),
+///     String::from("Builds the project"),
+///     build
+/// );
+/// # }
+/// ```
+/// Alternatively, a simpler initialization would be utilizing the [`From`] trait.
+/// ```rust
+/// use parsin::{Context, Subcommand};
+///
+/// # fn main() {
+/// let subcommand = Subcommand::from(("build", "Builds the project", Context::new()));
+/// # }
+/// ```
+/// Once parsed, the selected subcommand and its parsed data are found within
+/// [ParsedArguments](struct@crate::parser::ParsedArguments).
+/// ```rust
+/// use parsin::{parse_from, Context, Subcommand, Type};
+///
+/// # fn main() {
+/// let build = Context::from((
+///     &[],
+///     &[("--release", Type::Bool, "Builds with optimizations", false, None)],
+/// ));
+/// let mut ctx = Context::new();
+/// ctx.add_subcommand(Subcommand::from(("build", "Builds the project", build))).unwrap();
+///
+/// let parsed = parse_from(&ctx, ["build", "--release"]);
+/// assert_eq!(parsed.subcommand_path(), vec!["build"]);
+/// let (_, build) = parsed.subcommand.unwrap();
+/// assert!(*build.flags["--release"].unwrap_bool());
+/// # }
+/// ```
+/// `--help` given after a subcommand displays the help message of the subcommand,
+/// before any of the checks of its parent.
+/// ```rust
+/// use parsin::{try_parse_from, Context, Subcommand, Type};
+/// use parsin::errors::ErrorKind;
+///
+/// # fn main() {
+/// let build = Context::from((
+///     &[],
+///     &[("--release", Type::Bool, "Builds with optimizations", false, None)],
+/// ));
+/// let mut ctx = Context::from((&[], &[("--name", Type::Str, "Project name", true, None)]));
+/// ctx.add_subcommand(Subcommand::from(("build", "Builds the project", build))).unwrap();
+///
+/// let error = try_parse_from(&ctx, ["build", "--help"]).unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::WantsHelp);
+/// assert!(error.diagnosis().contains("--release"));
+///
+/// // `build` is the value of `--name` rather than the subcommand
+/// let error = try_parse_from(&ctx, ["--name", "build", "--help"]).unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::WantsHelp);
+/// assert!(!error.diagnosis().contains("--release"));
+/// assert!(error.diagnosis().contains("COMMANDS\n--------\n          build   Builds the project"));
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Subcommand {
+    /// The name used to select the subcommand
+    pub(crate) name: String,
+    /// Help message to display when listing the subcommands
+    pub(crate) help: String,
+    /// Arguments, flags and further subcommands of the subcommand
+    pub(crate) context: Context,
+}
+
+impl Subcommand {
+    /// Initializes an instance of Subcommand
+    pub fn new(name: String, help: String, context: Context) -> Self {
+        Self {
+            name,
+            help,
+            context,
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_description(&self) -> String {
+        self.help.clone()
+    }
+
+    pub fn get_context(&self) -> Context {
+        self.context.clone()
+    }
+}
+
+impl From<(&str, &str, Context)> for Subcommand {
+    fn from(_tuple: (&str, &str, Context)) -> Self {
+        Self::new(_tuple.0.to_string(), _tuple.1.to_string(), _tuple.2)
+    }
+}
+
+impl From<&(&str, &str, Context)> for Subcommand {
+    fn from(_tuple: &(&str, &str, Context)) -> Self {
+        Self::new(_tuple.0.to_string(), _tuple.1.to_string(), _tuple.2.clone())
+    }
+}
@@ -0,0 +1,28 @@
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result;
+use std::sync::Arc;
+
+/// Returns why the value given was rejected
+type Check = dyn Fn(&str) -> std::result::Result<(), String> + Send + Sync;
+
+/// A closure checking a value given to a [Flag](struct@crate::Flag)
+/// or an [Arg](struct@crate::Arg), returning why the value was rejected
+#[derive(Clone)]
+pub(crate) struct Validator(pub(crate) Arc<Check>);
+
+impl Validator {
+    pub(crate) fn new<F>(validator: F) -> Self
+    where
+        F: Fn(&str) -> std::result::Result<(), String> + Send + Sync + 'static,
+    {
+        Self(Arc::new(validator))
+    }
+}
+
+impl Debug for Validator {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        // closures cannot be displayed
+        write!(f, "Validator")
+    }
+}
@@ -7,9 +7,27 @@ use std::fmt::Result;
 pub enum ErrorKind {
     DuplicateFlag,
     DuplicateArgument,
+    DuplicateSubcommand,
+    DuplicateGroup,
     MissingArgument,
     MissingValue,
     MissingFlag,
+    MissingSubcommand,
+    MissingGroup,
+    MissingGroupMember,
+    UnexpectedValue,
+    UnknownFlag,
+    AmbiguousFlag,
+    UnexpectedArgument,
+    ArgumentConflict,
+    UndefinedName,
+    InvalidUtf8,
+    InvalidValue,
+    OutOfRange,
+    ResponseFile,
+    Config,
+    TooFewOccurrences,
+    TooManyOccurrences,
     WantsHelp,
     Other,
 }
@@ -3,8 +3,10 @@
 */
 use crate::errors::Error;
 use crate::errors::ErrorKind;
-use crate::get_env_args;
+use crate::Arg;
 use crate::Context;
+use crate::Group;
+use crate::Type;
 
 use std::process::exit;
 
@@ -14,14 +16,16 @@ use std::process::exit;
 /// |-------------------------------------|---------|----------------------------------------------|
 /// | &[`Context`](struct@crate::Context) | context | A reference to the context of the CLI parser |
 /// |-------------------------------------|---------|----------------------------------------------|
+/// | &[`str`]                            | bin     | The name the program was invoked with        |
+/// |-------------------------------------|---------|----------------------------------------------|
 /// | &[`Error`](struct@crate::errors::Error) | error | A reference to the error returned to throw |
-pub fn send_help_and_exit(context: &Context, error: &Error) -> ! {
+pub fn send_help_and_exit(context: &Context, bin: &str, error: &Error) -> ! {
     // WantsHelp error signifies that the client raised the `--help`
     // flag, thus the program will throw an advanced error for the client
     if error.kind() == ErrorKind::WantsHelp {
         // function exits on its own,
         // so it's not necessary to manually exit
-        send_advanced_help_and_exit(context);
+        send_advanced_help_and_exit(context, bin);
         // exit(1)  <<<    redundant code
     }
     let error_msg = format!(
@@ -34,26 +38,80 @@ For more information, try passing `--help`."#,
     exit(1);
 }
 
-/// Constructs a more advanced, descriptive version of the help message, stderrs,
-/// and exits
+/// Renders an argument the way it's displayed to the client, e.g. `<FILE>...`
+pub fn arg_usage(arg: &Arg) -> String {
+    let mut usage = format!("<{}>", arg.name.to_uppercase());
+    if arg.variadic {
+        usage += "...";
+    }
+    usage
+}
+
+/// Renders the bounds of an integer the way they're displayed to the client,
+/// e.g. `1..=65535`, `1..` or `..=10`
+pub fn range_usage(min: Option<i32>, max: Option<i32>) -> String {
+    let min: String = min.map(|m| m.to_string()).unwrap_or_default();
+    match max {
+        Some(max) => format!("{}..={}", min, max),
+        None => format!("{}..", min),
+    }
+}
+
+/// Renders a group the way it's displayed to the client, e.g. `[--json | --yaml]`,
+/// or `(--json | --yaml)` when a member must be given
+pub fn group_usage(context: &Context, group: &Group) -> String {
+    let members: Vec<String> = group
+        .members
+        .iter()
+        .map(|member| match context.get_arg(member) {
+            Some(arg) => arg_usage(&arg),
+            None => member.clone(),
+        })
+        .collect();
+    if group.required {
+        return format!("({})", members.join(" | "));
+    }
+    format!("[{}]", members.join(" | "))
+}
+
+/// Constructs a more advanced, descriptive version of the help message
 /// | Type   | Name | Description |
 /// |--------------------------------------|
 /// | &[`Context`] | context | A reference to the context fo the CLI parser |
-pub fn send_advanced_help_and_exit(context: &Context) -> ! {
+/// | &[`str`] | bin | The name the program was invoked with |
+pub fn render_advanced_help(context: &Context, bin: &str) -> String {
     let name: &String = &{
-        let bind = std::path::PathBuf::from(&get_env_args()[0]);
-        bind.file_name().unwrap().to_str().unwrap().to_string()
+        let bind = std::path::PathBuf::from(bin);
+        match bind.file_name() {
+            Some(file_name) => file_name.to_string_lossy().to_string(),
+            None => bin.to_string(),
+        }
     };
     let mut error_msg: String = String::new();
     error_msg += "Usage: ";
-    error_msg += &name;
+    error_msg += name;
     error_msg += " [OPTIONS] ";
-    if !context.args.is_empty() {
+    {
         let mut lines: Vec<String> = Vec::new();
+        for group in context.groups.iter() {
+            lines.push(group_usage(context, group));
+        }
+        // arguments within a group were rendered along with the group
         for arg in context.args.iter() {
-            lines.push(format!("<{}>", arg.name.to_uppercase()));
+            if context.groups.iter().any(|g| g.members.contains(&arg.name)) {
+                continue;
+            }
+            lines.push(arg_usage(arg));
+        }
+        if !context.subcommands.is_empty() {
+            lines.push("<COMMAND>".to_string());
+        }
+        if context.capture_trailing {
+            lines.push("[-- <TRAILING>...]".to_string());
         }
         error_msg += &lines.join(" ");
+    }
+    if !context.args.is_empty() {
         error_msg += r#"
 
 ARGUMENTS
@@ -61,11 +119,16 @@ ARGUMENTS
 "#;
         let mut lines: Vec<String> = Vec::new();
         for arg in context.args.iter() {
-            let mut buffer = format!(
-                "{:>15}   {}",
-                format!("<{}>", arg.name.to_uppercase()),
-                arg.help
-            );
+            let mut buffer = format!("{:>15}   {}", arg_usage(arg), arg.help);
+            if !arg.possible_values.is_empty() {
+                buffer += &format!(" [possible values: {}]", arg.possible_values.join(", "));
+            }
+            if arg.min_value.is_some() || arg.max_value.is_some() {
+                buffer += &format!(" [range: {}]", range_usage(arg.min_value, arg.max_value));
+            }
+            if let Some(var) = &arg.env {
+                buffer += &format!(" [env: {}]", var);
+            }
             if let Some(def) = &arg.default {
                 buffer += &format!(" [DEFAULT={}]", def);
             }
@@ -73,6 +136,18 @@ ARGUMENTS
         }
         error_msg += &lines.join("\n");
     }
+    if !context.subcommands.is_empty() {
+        error_msg += r#"
+
+COMMANDS
+--------
+"#;
+        let mut lines: Vec<String> = Vec::new();
+        for subcommand in context.subcommands.iter() {
+            lines.push(format!("{:>15}   {}", subcommand.name, subcommand.help));
+        }
+        error_msg += &lines.join("\n");
+    }
     // Options are ALWAYS going to display because
     // the `--help` flag will always exist
     error_msg += r#"
@@ -82,7 +157,29 @@ OPTIONS
 "#;
     let mut lines: Vec<String> = Vec::new();
     for flag in context.flags.iter() {
-        let mut buffer = format!("{:>15}   {}", flag.name, flag.help);
+        let mut usage = flag.name.clone();
+        if flag.negatable {
+            // `--color` is rendered as `--[no-]color`
+            let bare = flag.name.trim_start_matches('-');
+            let dashes = &flag.name[..flag.name.len() - bare.len()];
+            usage = format!("{}[no-]{}", dashes, bare);
+        }
+        if let Some(short) = flag.short {
+            usage = format!("-{}, {}", short, usage);
+        }
+        if flag.multiple || matches!(flag.r#type, Type::Count) {
+            usage += "...";
+        }
+        let mut buffer = format!("{:>15}   {}", usage, flag.help);
+        if !flag.possible_values.is_empty() {
+            buffer += &format!(" [possible values: {}]", flag.possible_values.join(", "));
+        }
+        if flag.min_value.is_some() || flag.max_value.is_some() {
+            buffer += &format!(" [range: {}]", range_usage(flag.min_value, flag.max_value));
+        }
+        if let Some(var) = &flag.env {
+            buffer += &format!(" [env: {}]", var);
+        }
         if let Some(def) = &flag.default {
             buffer += &format!(" [DEFAULT={}]", def);
         }
@@ -90,6 +187,21 @@ OPTIONS
     }
     lines.push(format!("{:>15}   Displays this message", "--help"));
     error_msg += &lines.join("\n");
-    eprintln!("{}", error_msg);
+    if !context.subcommands.is_empty() {
+        error_msg += &format!(
+            "\n\nFor more information on a command, try `{} <COMMAND> --help`.",
+            name
+        );
+    }
+    error_msg
+}
+
+/// Stderrs the advanced help message and exits
+/// | Type   | Name | Description |
+/// |--------------------------------------|
+/// | &[`Context`] | context | A reference to the context fo the CLI parser |
+/// | &[`str`] | bin | The name the program was invoked with |
+pub fn send_advanced_help_and_exit(context: &Context, bin: &str) -> ! {
+    eprintln!("{}", render_advanced_help(context, bin));
     exit(1);
 }
@@ -113,20 +113,71 @@ pub mod parser;
 pub use builder::arg::Arg;
 pub use builder::context::Context;
 pub use builder::flag::Flag;
+pub use builder::group::Group;
+pub use builder::subcommand::Subcommand;
 pub use parser::parse;
+pub use parser::parse_from;
+pub use parser::parse_from_argv;
+pub use parser::try_parse;
+pub use parser::try_parse_from;
+pub use parser::try_parse_from_argv;
 
 // Use of the standard library
 use std::env::args;
+use std::env::args_os;
+use std::ffi::OsString;
 
 /// Obtains the Command Line arguments
+///
+/// # Panics
+/// Panics if any argument is not valid UTF-8, see [`get_env_args_os`]
 pub fn get_env_args() -> Vec<String> {
     args().collect::<Vec<String>>()
 }
 
+/// Obtains the Command Line arguments as they were given, without
+/// panicking on non UTF-8 data
+pub fn get_env_args_os() -> Vec<OsString> {
+    args_os().collect::<Vec<OsString>>()
+}
+
 /// Defines what to **parse** an argument into
 #[derive(Debug, Clone)]
 pub enum Type {
     Int,
     Str,
     Bool,
+    /// A floating-point number, NaN and infinite numbers are
+    /// rejected unless allowed by the flag or argument
+    Float,
+    /// A flag that takes no value and counts how many times it was given, e.g. `-vvv`
+    Count,
+    /// A path, or any other value that is preserved as it was given,
+    /// even if it's not valid UTF-8, which every other type rejects
+    /// ```rust
+    /// # #[cfg(unix)]
+    /// # fn main() {
+    /// use parsin::{try_parse_from, Context, Flag, Type};
+    /// use parsin::errors::ErrorKind;
+    /// use std::ffi::OsString;
+    /// use std::os::unix::ffi::OsStringExt;
+    ///
+    /// let ctx = Context::from((
+    ///     &[],
+    ///     &[
+    ///         Flag::from(("--file", Type::Path, "File to read", false, None)),
+    ///         Flag::from(("--name", Type::Str, "Your name", false, None)),
+    ///     ],
+    /// ));
+    /// let file = OsString::from_vec(vec![b'a', 0xff, b'b']);
+    /// let parsed = try_parse_from(&ctx, [OsString::from("--file"), file.clone()]).unwrap();
+    /// assert_eq!(parsed.flags["--file"].unwrap_path().as_os_str(), file.as_os_str());
+    ///
+    /// let error = try_parse_from(&ctx, [OsString::from("--name"), file.clone()]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::InvalidUtf8);
+    /// # }
+    /// # #[cfg(not(unix))]
+    /// # fn main() {}
+    /// ```
+    Path,
 }
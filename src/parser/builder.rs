@@ -1,4 +1,6 @@
 use std::collections::HashMap;
+use std::ffi::OsString;
+use std::path::PathBuf;
 
 /// Encapsulates the returned parsed argument
 #[derive(Debug, Clone)]
@@ -6,18 +8,18 @@ pub enum Value {
     Str(String),
     Bool(bool),
     Int(i32),
+    Float(f64),
+    List(Vec<Value>),
+    Path(PathBuf),
     Null,
 }
 
 impl Value {
     pub fn is_null(&self) -> bool {
-        match self {
-            Value::Null => true,
-            _ => false,
-        }
+        matches!(self, Value::Null)
     }
 
-    pub fn unwrap_str<'a>(&'a self) -> &'a String {
+    pub fn unwrap_str(&self) -> &String {
         match self {
             Value::Str(v) => v,
             _ => panic!("Unwrapped the wrong variant"),
@@ -31,7 +33,7 @@ impl Value {
         self.unwrap_str()
     }
 
-    pub fn unwrap_bool<'a>(&'a self) -> &'a bool {
+    pub fn unwrap_bool(&self) -> &bool {
         match self {
             Value::Bool(v) => v,
             _ => panic!("Unwrapped the wrong variant"),
@@ -58,6 +60,48 @@ impl Value {
         }
         self.unwrap_int()
     }
+
+    pub fn unwrap_float(&self) -> &f64 {
+        match self {
+            Value::Float(v) => v,
+            _ => panic!("Unwrapped the wrong variant"),
+        }
+    }
+
+    pub fn unwrap_float_or<'a>(&'a self, other : &'a f64) -> &'a f64 {
+        if self.is_null() {
+            return other;
+        }
+        self.unwrap_float()
+    }
+
+    pub fn unwrap_list(&self) -> &Vec<Value> {
+        match self {
+            Value::List(v) => v,
+            _ => panic!("Unwrapped the wrong variant"),
+        }
+    }
+
+    pub fn unwrap_list_or<'a>(&'a self, other : &'a Vec<Value>) -> &'a Vec<Value> {
+        if self.is_null() {
+            return other;
+        }
+        self.unwrap_list()
+    }
+
+    pub fn unwrap_path(&self) -> &PathBuf {
+        match self {
+            Value::Path(v) => v,
+            _ => panic!("Unwrapped the wrong variant"),
+        }
+    }
+
+    pub fn unwrap_path_or<'a>(&'a self, other : &'a PathBuf) -> &'a PathBuf {
+        if self.is_null() {
+            return other;
+        }
+        self.unwrap_path()
+    }
 }
 
 /// The returned parsed data
@@ -65,6 +109,15 @@ impl Value {
 pub struct ParsedArguments {
     pub arguments: HashMap<String, Value>,
     pub flags: HashMap<String, Value>,
+    /// The selected subcommand, if any, along with its own parsed data
+    pub subcommand: Option<(String, Box<ParsedArguments>)>,
+    /// Arguments given after `--`, when captured by the context
+    pub trailing: Vec<OsString>,
+    /// Positional arguments given beyond the ones defined, when allowed by the context
+    pub extra_arguments: Vec<OsString>,
+    /// Flags not defined along with their apparent values, in the order they were given,
+    /// when allowed by the context
+    pub unknown_flags: Vec<OsString>,
 }
 
 impl ParsedArguments {
@@ -72,7 +125,22 @@ impl ParsedArguments {
         Self {
             arguments: HashMap::new(),
             flags: HashMap::new(),
+            subcommand: None,
+            trailing: Vec::new(),
+            extra_arguments: Vec::new(),
+            unknown_flags: Vec::new(),
+        }
+    }
+
+    /// Names of the selected subcommands, from the outermost to the innermost
+    pub fn subcommand_path(&self) -> Vec<String> {
+        let mut path: Vec<String> = Vec::new();
+        let mut current = &self.subcommand;
+        while let Some((name, parsed)) = current {
+            path.push(name.clone());
+            current = &parsed.subcommand;
         }
+        path
     }
 }
 
@@ -1,88 +1,1070 @@
+use crate::builder::validator::Validator;
 use crate::errors::Error;
 use crate::errors::ErrorKind;
+use crate::help::arg_usage;
+use crate::help::range_usage;
+use crate::help::render_advanced_help;
 use crate::parser::ParsedArguments;
 use crate::parser::Value;
+use crate::Arg;
 use crate::Context;
+use crate::Flag;
 use crate::Type;
 
-pub(crate) fn _look_for_help(args: &[String]) -> Result<(), Error> {
-    if args.contains(&"--help".to_string()) {
-        return Err(Error::new(ErrorKind::WantsHelp, String::new()));
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::ffi::OsString;
+use std::ops::Range;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Maximum amount of response files including one another
+const MAX_RESPONSE_FILE_DEPTH: usize = 16;
+
+/// Replaces every `@path` argument with the arguments contained within the file,
+/// when enabled by the context. Arguments given after `--` are left as they are
+pub(crate) fn _expand_response_files(
+    args: &[OsString],
+    ctx: &Context,
+) -> Result<Vec<OsString>, Error> {
+    if !ctx.expand_response_files {
+        return Ok(args.to_vec());
+    }
+    let mut __expanded: Vec<OsString> = Vec::with_capacity(args.len());
+    let mut __stack: Vec<PathBuf> = Vec::new();
+    let mut __ended: bool = false;
+    for arg in args.iter() {
+        if __ended || arg == "--" {
+            __ended = true;
+            __expanded.push(arg.clone());
+            continue;
+        }
+        __expand_argument(arg, None, &mut __stack, &mut __expanded, &mut __ended)?;
+    }
+    Ok(__expanded)
+}
+
+/// Expands a single argument into `expanded`, `origin` being the
+/// response file and line the argument was read from, if any
+fn __expand_argument(
+    arg: &OsStr,
+    origin: Option<(&Path, usize)>,
+    stack: &mut Vec<PathBuf>,
+    expanded: &mut Vec<OsString>,
+    ended: &mut bool,
+) -> Result<(), Error> {
+    let bytes = arg.as_encoded_bytes();
+    if *ended || bytes.len() < 2 || bytes[0] != b'@' {
+        *ended = *ended || arg == "--";
+        expanded.push(arg.to_os_string());
+        return Ok(());
+    }
+    // the leading `@` was checked above
+    let path = PathBuf::from(__raw_suffix(arg, "@").unwrap());
+    let location: String = match origin {
+        Some((file, line)) => format!(" (included from `{}` at line {})", file.display(), line),
+        None => String::new(),
+    };
+    if stack.len() >= MAX_RESPONSE_FILE_DEPTH {
+        return Err(Error::new(
+            ErrorKind::ResponseFile,
+            format!(
+                "Response file `{}`{} exceeds the maximum nesting depth of {}",
+                path.display(),
+                location,
+                MAX_RESPONSE_FILE_DEPTH
+            ),
+        ));
+    }
+    // comparing canonical paths so that the same file reached
+    // through different paths is still detected as a cycle
+    let canonical: PathBuf = path.canonicalize().unwrap_or_else(|_| path.clone());
+    if stack.contains(&canonical) {
+        return Err(Error::new(
+            ErrorKind::ResponseFile,
+            format!(
+                "Response file `{}`{} includes itself",
+                path.display(),
+                location
+            ),
+        ));
+    }
+    let content: String = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(error) => {
+            return Err(Error::new(
+                ErrorKind::ResponseFile,
+                format!(
+                    "Could not read response file `{}`{}: {}",
+                    path.display(),
+                    location,
+                    error
+                ),
+            ));
+        }
+    };
+    stack.push(canonical);
+    for (token, line) in __split_response_file(&content, &path)? {
+        __expand_argument(
+            OsStr::new(&token),
+            Some((&path, line)),
+            stack,
+            expanded,
+            ended,
+        )?;
+    }
+    stack.pop();
+    Ok(())
+}
+
+/// Splits the content of a response file into arguments along with the line they start at.
+///
+/// Arguments are separated by whitespace, may be quoted with either `'` or `"`
+/// and a backslash escapes the following character outside of single quotes
+fn __split_response_file(content: &str, path: &Path) -> Result<Vec<(String, usize)>, Error> {
+    let mut __tokens: Vec<(String, usize)> = Vec::new();
+    let mut __current: Option<(String, usize)> = None;
+    let mut __quote: Option<(char, usize)> = None;
+    let mut __line: usize = 1;
+    let mut __chars = content.chars();
+    while let Some(c) = __chars.next() {
+        let start: usize = __line;
+        if c == '\n' {
+            __line += 1;
+        }
+        match (__quote, c) {
+            (Some((q, _)), c) if c == q => __quote = None,
+            (Some(('\'', _)), c) => __current.get_or_insert((String::new(), start)).0.push(c),
+            (_, '\\') => match __chars.next() {
+                // an escaped line break continues the line
+                Some('\n') => __line += 1,
+                Some(escaped) => __current
+                    .get_or_insert((String::new(), start))
+                    .0
+                    .push(escaped),
+                None => {}
+            },
+            (Some(_), c) => __current.get_or_insert((String::new(), start)).0.push(c),
+            (None, '\'' | '"') => {
+                // an empty pair of quotes is still an argument
+                __current.get_or_insert((String::new(), start));
+                __quote = Some((c, start));
+            }
+            (None, c) if c.is_whitespace() => {
+                if let Some(token) = __current.take() {
+                    __tokens.push(token);
+                }
+            }
+            (None, c) => __current.get_or_insert((String::new(), start)).0.push(c),
+        }
+    }
+    if let Some((_, line)) = __quote {
+        return Err(Error::new(
+            ErrorKind::ResponseFile,
+            format!(
+                "Unterminated quote in response file `{}` at line {}",
+                path.display(),
+                line
+            ),
+        ));
+    }
+    if let Some(token) = __current.take() {
+        __tokens.push(token);
+    }
+    Ok(__tokens)
+}
+
+/// Looks for the `--help` flag among the flags of the context, then among
+/// the flags of the selected subcommand, recursively, before anything is checked
+pub(crate) fn _look_for_help(args: &[OsString], ctx: &Context, bin: &str) -> Result<(), Error> {
+    // arguments are split apart the way they are parsed, so that neither the value
+    // of a flag nor the arguments given after `--` are mistaken for `--help`
+    // or a subcommand. Errors are left to the parsing itself
+    let tokens: Tokens = match _arguments_and_options(args, ctx) {
+        Ok(tokens) => tokens,
+        Err(_) => return Ok(()),
+    };
+    if tokens.help {
+        // the rendered help message is carried within the error
+        // so that the caller can decide what to do with it
+        return Err(Error::new(
+            ErrorKind::WantsHelp,
+            render_advanced_help(ctx, bin),
+        ));
+    }
+    // arguments given after a subcommand belong to the subcommand
+    if let Some((name, idx)) = tokens.subcommand {
+        let sub = ctx.get_subcommand(&name).unwrap();
+        let sub_bin = format!("{} {}", bin, name);
+        return _look_for_help(&args[idx + 1..], &sub.context, &sub_bin);
     }
     Ok(())
 }
 
-fn __find_and_remove<T: PartialEq>(list: &mut Vec<T>, item: &T) -> Option<T> {
-    // if item not in the list or is empty
-    let llen = list.len();
-    if !list.contains(item) || llen == 0 {
+/// A flag found within the given arguments, along with
+/// its value when the flag's type requires one
+#[derive(Debug, Clone)]
+pub(crate) struct Occurrence {
+    pub(crate) flag: Flag,
+    pub(crate) value: Option<OsString>,
+    /// Signals that the `--no-` counterpart of the flag was given
+    pub(crate) negated: bool,
+}
+
+impl Occurrence {
+    pub(crate) fn new(flag: Flag, value: Option<OsString>) -> Self {
+        Self {
+            flag,
+            value,
+            negated: false,
+        }
+    }
+}
+
+/// The given arguments split apart by [`_arguments_and_options`]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Tokens {
+    /// Positional arguments
+    pub(crate) args: Vec<OsString>,
+    /// Index of each positional argument within the given arguments
+    pub(crate) positions: Vec<usize>,
+    /// Flags in the order they were given
+    pub(crate) occurrences: Vec<Occurrence>,
+    /// Name of the selected subcommand along with its index, every
+    /// argument after it belongs to the subcommand
+    pub(crate) subcommand: Option<(String, usize)>,
+    /// Arguments given after `--`, when captured by the context
+    pub(crate) trailing: Vec<OsString>,
+    /// Flags not defined within Context along with their apparent values,
+    /// when allowed by the context
+    pub(crate) unknown_flags: Vec<OsString>,
+    /// Signals that `--help` was given among the flags
+    pub(crate) help: bool,
+}
+
+/// Looks for a flag either by its name, its `--no-` counterpart or by its short form, e.g. `-v`
+fn __lookup_flag(token: &str, ctx: &Context) -> Option<Flag> {
+    if let Some(flag) = ctx.get_flag(token) {
+        return Some(flag);
+    }
+    if let Some(flag) = ctx.get_flag_by_negation(token) {
+        return Some(flag);
+    }
+    let mut chars = token.chars();
+    if chars.next() != Some('-') {
         return None;
     }
-    // this is an anonymous function, it does NOT return
-    // for the outter function
-    let index: usize = (|l: &[T], i: &T| {
-        if l.len() == 1 {
-            return 0;
+    match (chars.next(), chars.next()) {
+        (Some(short), None) => ctx.get_flag_by_short(short),
+        _ => None,
+    }
+}
+
+/// Looks for a flag by its name or its `--no-` counterpart, returning whether it was negated.
+///
+/// When allowed by the context, a long flag may also be abbreviated to any
+/// unambiguous prefix, e.g. `--verb` for `--verbose`
+fn __resolve_long(token: &str, ctx: &Context) -> Result<Option<(Flag, bool)>, Error> {
+    if let Some(flag) = ctx.get_flag(token) {
+        return Ok(Some((flag, false)));
+    }
+    if let Some(flag) = ctx.get_flag_by_negation(token) {
+        return Ok(Some((flag, true)));
+    }
+    if !ctx.allow_prefix_matching || !token.starts_with("--") || token.len() < 3 {
+        return Ok(None);
+    }
+    let mut __matches: Vec<(Flag, bool, String)> = Vec::new();
+    for flag in ctx.flags.iter() {
+        if flag.name.starts_with("--") && flag.name.starts_with(token) {
+            __matches.push((flag.clone(), false, flag.name.clone()));
         }
-        let mut bind: usize = 0;
-        for idx in 0..llen {
-            if list[idx] == *i {
-                bind = idx;
-                break;
+        if let Some(negated) = flag.get_negated_name() {
+            if negated.starts_with("--") && negated.starts_with(token) {
+                __matches.push((flag.clone(), true, negated));
             }
         }
-        bind
-    })(list, item);
-    Some(list.remove(index))
+    }
+    match __matches.len() {
+        0 => Ok(None),
+        1 => {
+            let (flag, negated, _) = __matches.remove(0);
+            Ok(Some((flag, negated)))
+        }
+        _ => {
+            let candidates: Vec<String> = __matches
+                .iter()
+                .map(|(_, _, name)| format!("`{}`", name))
+                .collect();
+            Err(Error::new(
+                ErrorKind::AmbiguousFlag,
+                format!(
+                    "Flag `{}` is ambiguous, it could be any of: {}",
+                    token,
+                    candidates.join(", ")
+                ),
+            ))
+        }
+    }
+}
+
+/// The remainder of a raw argument after `prefix`, preserving non UTF-8 data.
+///
+/// Returns `None` when the raw argument does not start with `prefix`, which happens
+/// when the lossy version of the argument replaced non UTF-8 data within the prefix
+/// ```rust
+/// # #[cfg(unix)]
+/// # fn main() {
+/// use parsin::{try_parse_from, Context, Flag, Type};
+/// use parsin::errors::ErrorKind;
+/// use std::ffi::OsString;
+/// use std::os::unix::ffi::OsStringExt;
+///
+/// // the replacement character of the lossy version must not match a short form
+/// let ctx = Context::from((
+///     &[],
+///     &[
+///         Flag::from(("--odd", Type::Bool, "Odd short form", false, None)).short('\u{FFFD}'),
+///         Flag::from(("--output", Type::Str, "File to write", false, None)).short('o'),
+///     ],
+/// ));
+/// let cluster = OsString::from_vec(vec![b'-', 0xff, b'o', b'x']);
+/// let error = try_parse_from(&ctx, [cluster]).unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::UnknownFlag);
+/// # }
+/// # #[cfg(not(unix))]
+/// # fn main() {}
+/// ```
+fn __raw_suffix(raw: &OsStr, prefix: &str) -> Option<OsString> {
+    let bytes = raw.as_encoded_bytes();
+    if prefix.is_empty() || !bytes.starts_with(prefix.as_bytes()) {
+        return None;
+    }
+    // SAFETY: the bytes come from `OsStr::as_encoded_bytes` and are split right
+    // after `prefix`, which was just checked to be a valid, non-empty UTF-8 substring
+    let suffix = unsafe { OsStr::from_encoded_bytes_unchecked(&bytes[prefix.len()..]) };
+    Some(suffix.to_os_string())
 }
 
-pub(crate) fn _arguments_and_options(args: &[String], ctx: &Context) -> (Vec<String>, Vec<String>) {
-    let mut __args: Vec<String> = args.to_vec();
-    let mut __f_v: Vec<String> = Vec::new();
+/// Takes the value of a flag that requires one, starting at `pointer`.
+/// Returns the value along with the amount of arguments consumed
+fn __take_value(
+    args: &[String],
+    raw: &[OsString],
+    pointer: usize,
+    flag: &Flag,
+    ctx: &Context,
+) -> Result<(OsString, usize), Error> {
+    let a_len = args.len();
+    if pointer >= a_len {
+        return Err(Error::new(
+            ErrorKind::MissingValue,
+            format!("Missing value for `{}`.", flag.name),
+        ));
+    }
+    // if the value is "--", the actual value comes right after
+    if args[pointer] == "--" {
+        // Lets say the value was "--", but no value was provided after
+        if pointer + 1 >= a_len {
+            return Err(Error::new(
+                ErrorKind::MissingValue,
+                format!("Missing value for `{}`", &flag.name),
+            ));
+        }
+        return Ok((raw[pointer + 1].clone(), 2));
+    }
+    // means value is a defined flag, possibly abbreviated or ambiguous
+    let abbreviated: bool =
+        ctx.allow_prefix_matching && !matches!(__resolve_long(&args[pointer], ctx), Ok(None));
+    if __lookup_flag(&args[pointer], ctx).is_some() || abbreviated {
+        let next_iter = &args[pointer];
+        return Err(Error::new(
+            ErrorKind::MissingValue,
+            format!(
+                "Missing value for `{}`. \
+if you want to pass `{}` as the value, try `-- {}`.",
+                flag.name, next_iter, next_iter
+            ),
+        ));
+    }
+    Ok((raw[pointer].clone(), 1))
+}
+
+/// Splits an argument such as `--flag=value` or `-f=value` on the first `=`.
+/// Returns `None` when the part before `=` is not a known flag
+fn __split_attached(token: &str, raw: &OsStr, ctx: &Context) -> Result<Option<Occurrence>, Error> {
+    let (name, _) = match token.split_once('=') {
+        Some(pair) => pair,
+        None => return Ok(None),
+    };
+    let flag: Flag = match __resolve_long(name, ctx)? {
+        Some((_, true)) => {
+            return Err(Error::new(
+                ErrorKind::UnexpectedValue,
+                format!(
+                    "Flag `{}` does not take a value, but `{}` was given",
+                    name, token
+                ),
+            ));
+        }
+        Some((flag, false)) => flag,
+        // looking for a short flag, e.g. `-o=value`
+        None => match __lookup_flag(name, ctx) {
+            Some(flag) => flag,
+            None => return Ok(None),
+        },
+    };
+    match flag.r#type {
+        Type::Bool | Type::Count => Err(Error::new(
+            ErrorKind::UnexpectedValue,
+            format!(
+                "Flag `{}` does not take a value, but `{}` was given",
+                flag.name, token
+            ),
+        )),
+        Type::Str | Type::Int | Type::Float | Type::Path => {
+            match __raw_suffix(raw, &token[..name.len() + 1]) {
+                Some(value) => Ok(Some(Occurrence::new(flag, Some(value)))),
+                // the flag name only matched once non UTF-8 data was replaced
+                None => Ok(None),
+            }
+        }
+    }
+}
+
+/// Expands a cluster of short flags such as `-xvf file` or `-ofile`.
+///
+/// Every flag within the cluster must be a `Type::Bool` or `Type::Count` flag except
+/// for the last one, which may take a value either attached to it or
+/// as the next argument, e.g. `-xofile` or `-xo=file`. Returns `None` when the argument is not a cluster
+/// of known short flags, along with the amount of arguments consumed otherwise
+fn __expand_cluster(
+    args: &[String],
+    raw: &[OsString],
+    pointer: usize,
+    ctx: &Context,
+) -> Result<Option<(Vec<Occurrence>, usize)>, Error> {
+    let token = &args[pointer];
+    if !token.starts_with('-') || token.starts_with("--") || token.len() < 2 {
+        return Ok(None);
+    }
+    let body = &token[1..];
+    let mut __occurrences: Vec<Occurrence> = Vec::new();
+    for (idx, short) in body.char_indices() {
+        let flag = match ctx.get_flag_by_short(short) {
+            Some(flag) => flag,
+            None => return Ok(None),
+        };
+        match flag.r#type {
+            Type::Bool | Type::Count => {
+                __occurrences.push(Occurrence::new(flag, None));
+            }
+            // type of flag that must contain a value,
+            // which is the remainder of the cluster or the next argument
+            Type::Str | Type::Int | Type::Float | Type::Path => {
+                // skipping the leading `-` along with the short flags so far
+                let mut start = 1 + idx + short.len_utf8();
+                // allowing the `-o=value` syntax within clusters
+                if body[idx + short.len_utf8()..].starts_with('=') {
+                    start += 1;
+                }
+                let value = match __raw_suffix(&raw[pointer], &token[..start]) {
+                    Some(value) => value,
+                    // the short flags only matched once non UTF-8 data was replaced
+                    None => return Ok(None),
+                };
+                if !value.is_empty() {
+                    __occurrences.push(Occurrence::new(flag, Some(value)));
+                    return Ok(Some((__occurrences, 1)));
+                }
+                let (value, consumed) = __take_value(args, raw, pointer + 1, &flag, ctx)?;
+                __occurrences.push(Occurrence::new(flag, Some(value)));
+                return Ok(Some((__occurrences, 1 + consumed)));
+            }
+        }
+    }
+    // same goes for a cluster made of flags taking no value
+    if raw[pointer].to_str().is_none() {
+        return Ok(None);
+    }
+    Ok(Some((__occurrences, 1)))
+}
+
+/// Levenshtein distance between two strings
+fn __edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current: Vec<usize> = vec![i + 1];
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            let insertion = current[j] + 1;
+            let deletion = previous[j + 1] + 1;
+            current.push(substitution.min(insertion).min(deletion));
+        }
+        previous = current;
+    }
+    previous[b_chars.len()]
+}
+
+/// Looks for the candidate closest to what was given,
+/// as long as it's close enough to be considered a typo
+fn __suggest(given: &str, candidates: &[String]) -> Option<String> {
+    // leading dashes are not taken into account
+    let given = given.trim_start_matches('-');
+    let threshold = (given.chars().count() / 3).max(2);
+    let mut best: Option<(usize, &String)> = None;
+    for candidate in candidates.iter() {
+        let distance = __edit_distance(given, candidate.trim_start_matches('-'));
+        if distance > threshold {
+            continue;
+        }
+        if best.is_none() || distance < best.unwrap().0 {
+            best = Some((distance, candidate));
+        }
+    }
+    best.map(|(_, candidate)| candidate.clone())
+}
+
+/// Checks if an argument is meant to be a flag, negative numbers are not
+fn __looks_like_flag(token: &str) -> bool {
+    if !token.starts_with('-') || token.len() < 2 {
+        return false;
+    }
+    let body = &token[1..];
+    // `inf` and `nan` are numbers as well, e.g. `-inf`
+    let is_number = !body.starts_with(['-', '+']) && body.parse::<f64>().is_ok();
+    !is_number
+}
+
+/// Builds the error for a flag not defined within Context,
+/// suggesting the closest flag or subcommand
+fn __unknown_flag(token: &str, ctx: &Context) -> Error {
+    let name = match token.split_once('=') {
+        Some((name, _)) => name,
+        None => token,
+    };
+    let mut candidates: Vec<String> = Vec::new();
+    for flag in ctx.flags.iter() {
+        candidates.push(flag.name.clone());
+        if let Some(negated) = flag.get_negated_name() {
+            candidates.push(negated);
+        }
+    }
+    for subcommand in ctx.subcommands.iter() {
+        candidates.push(subcommand.name.clone());
+    }
+    let mut diagnosis = format!("Unknown flag `{}`", name);
+    if let Some(suggestion) = __suggest(name, &candidates) {
+        diagnosis += &format!("\n\n  tip: a similar one exists: `{}`", suggestion);
+    }
+    Error::new(ErrorKind::UnknownFlag, diagnosis)
+}
+
+/// Splits the given arguments into positional arguments, flag occurrences
+/// and the arguments belonging to a subcommand
+pub(crate) fn _arguments_and_options(raw: &[OsString], ctx: &Context) -> Result<Tokens, Error> {
+    // flags are looked up through a lossy representation of the arguments,
+    // while values are taken from the raw arguments
+    let args: Vec<String> = raw
+        .iter()
+        .map(|a| a.to_string_lossy().to_string())
+        .collect();
+    let args: &[String] = &args;
+    let mut __args: Vec<OsString> = Vec::new();
+    let mut __positions: Vec<usize> = Vec::new();
+    let mut __occurrences: Vec<Occurrence> = Vec::new();
+    let mut __subcommand: Option<(String, usize)> = None;
+    let mut __trailing: Vec<OsString> = Vec::new();
+    let mut __unknown_flags: Vec<OsString> = Vec::new();
+    let mut __help: bool = false;
     let mut __pointer: usize = 0;
     let a_len = args.len();
     while __pointer < a_len {
-        if let Some(flag) = ctx.get_flag(&args[__pointer]) {
+        // a standalone `--` signals the end of the flags,
+        // everything after it is a positional argument
+        if args[__pointer] == "--" {
+            let rest = &raw[__pointer + 1..];
+            if ctx.capture_trailing {
+                __trailing.extend_from_slice(rest);
+                break;
+            }
+            __args.extend_from_slice(rest);
+            __positions.extend(__pointer + 1..a_len);
+            break;
+        }
+        // nothing given after `--help` matters, as only the help message is displayed
+        if args[__pointer] == "--help" {
+            __help = true;
+            break;
+        }
+        if let Some((flag, negated)) = __resolve_long(&args[__pointer], ctx)? {
+            if negated {
+                __occurrences.push(Occurrence {
+                    negated: true,
+                    ..Occurrence::new(flag, None)
+                });
+                __pointer += 1;
+                continue;
+            }
             match flag.r#type {
-                Type::Bool => {
-                    let bind = __find_and_remove(&mut __args, &args[__pointer]).unwrap();
-                    __f_v.push(bind);
+                Type::Bool | Type::Count => {
+                    __occurrences.push(Occurrence::new(flag, None));
                     __pointer += 1;
                 }
                 // type of flag that must contain a value
-                Type::Str | Type::Int => {
-                    let bind = __find_and_remove(&mut __args, &args[__pointer]).unwrap();
-                    __f_v.push(bind);
-                    if __pointer + 1 < a_len {
-                        let bind = __find_and_remove(&mut __args, &args[__pointer + 1]).unwrap();
-                        __f_v.push(bind);
-                        if &args[__pointer + 1] == "--" && __pointer + 2 < a_len {
-                            let bind =
-                                __find_and_remove(&mut __args, &args[__pointer + 2]).unwrap();
-                            __f_v.push(bind);
-                            __pointer += 3;
-                            continue;
-                        }
-                    }
-                    __pointer += 2;
+                Type::Str | Type::Int | Type::Float | Type::Path => {
+                    let (value, consumed) = __take_value(args, raw, __pointer + 1, &flag, ctx)?;
+                    __occurrences.push(Occurrence::new(flag, Some(value)));
+                    __pointer += 1 + consumed;
                 }
             }
             // end of match statement
             // trying to avoid using else statements
             continue;
         }
-        // ELSE:
+        if let Some(occurrence) = __split_attached(&args[__pointer], &raw[__pointer], ctx)? {
+            __occurrences.push(occurrence);
+            __pointer += 1;
+            continue;
+        }
+        if let Some((mut cluster, consumed)) = __expand_cluster(args, raw, __pointer, ctx)? {
+            __occurrences.append(&mut cluster);
+            __pointer += consumed;
+            continue;
+        }
+        if __looks_like_flag(&args[__pointer]) {
+            if !ctx.allow_unknown_flags {
+                return Err(__unknown_flag(&args[__pointer], ctx));
+            }
+            // preserving the unknown flag as it is, along with the next
+            // argument as its apparent value, unless the value is attached
+            __unknown_flags.push(raw[__pointer].clone());
+            __pointer += 1;
+            if args[__pointer - 1].contains('=') || __pointer >= a_len {
+                continue;
+            }
+            let next_iter = &args[__pointer];
+            if next_iter == "--"
+                || __looks_like_flag(next_iter)
+                || ctx.contains_subcommand(next_iter)
+            {
+                continue;
+            }
+            __unknown_flags.push(raw[__pointer].clone());
+            __pointer += 1;
+            continue;
+        }
+        // a subcommand can only come after the arguments defined within
+        // Context, everything after it belongs to the subcommand
+        if __args.len() <= ctx.args.len() && ctx.contains_subcommand(&args[__pointer]) {
+            __subcommand = Some((args[__pointer].clone(), __pointer));
+            break;
+        }
+        // ELSE: it's a positional argument
+        __args.push(raw[__pointer].clone());
+        __positions.push(__pointer);
         __pointer += 1;
     }
-    (__args, __f_v)
+    Ok(Tokens {
+        args: __args,
+        positions: __positions,
+        occurrences: __occurrences,
+        subcommand: __subcommand,
+        trailing: __trailing,
+        unknown_flags: __unknown_flags,
+        help: __help,
+    })
 }
 
-pub(crate) fn _check_mandatory_args(args: &[String], ctx: &Context) -> Result<(), Error> {
-    let a_len = args.len();
+/// Signals if the flag or argument named `name` was given on the command line
+fn __is_present(name: &str, tokens: &Tokens, ctx: &Context) -> bool {
+    if let Some(idx) = ctx.args.iter().position(|arg| arg.name == name) {
+        let ranges = __distribute_arguments(tokens.args.len(), ctx);
+        return !ranges[idx].is_empty();
+    }
+    tokens.occurrences.iter().any(|o| o.flag.name == name)
+}
+
+/// Signals if the flag or argument named `name` was provided, either on the
+/// command line, through its environment variable or within the configuration file
+fn __is_provided(name: &str, tokens: &Tokens, config: &Config, ctx: &Context) -> bool {
+    if __is_present(name, tokens, ctx) || config.entries.contains_key(name) {
+        return true;
+    }
+    match ctx.get_arg(name) {
+        Some(arg) => __env_is_set(&arg.env),
+        None => ctx
+            .get_flag(name)
+            .is_some_and(|flag| __env_is_set(&flag.env)),
+    }
+}
+
+/// Renders a flag or argument named `name` the way it's displayed to the client
+fn __member_usage(name: &str, ctx: &Context) -> String {
+    match ctx.get_arg(name) {
+        Some(arg) => arg_usage(&arg),
+        None => name.to_string(),
+    }
+}
+
+pub(crate) fn _check_groups(tokens: &Tokens, ctx: &Context) -> Result<(), Error> {
+    for group in ctx.groups.iter() {
+        let given: Vec<String> = group
+            .members
+            .iter()
+            .filter(|member| __is_present(member, tokens, ctx))
+            .map(|member| format!("`{}`", __member_usage(member, ctx)))
+            .collect();
+        if given.len() > 1 {
+            return Err(Error::new(
+                ErrorKind::ArgumentConflict,
+                format!(
+                    "{} cannot be used together, as they belong to the group `{}`",
+                    given.join(" and "),
+                    group.name
+                ),
+            ));
+        }
+        if group.required && given.is_empty() {
+            let members: Vec<String> = group
+                .members
+                .iter()
+                .map(|member| format!("`{}`", __member_usage(member, ctx)))
+                .collect();
+            return Err(Error::new(
+                ErrorKind::MissingGroupMember,
+                format!(
+                    "One of {} must be given, as required by the group `{}`",
+                    members.join(", "),
+                    group.name
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn _check_relations(
+    tokens: &Tokens,
+    config: &Config,
+    ctx: &Context,
+) -> Result<(), Error> {
+    let mut relations: Vec<(&String, &Vec<String>, &Vec<String>)> = Vec::new();
+    for arg in ctx.args.iter() {
+        relations.push((&arg.name, &arg.requires, &arg.conflicts_with));
+    }
+    for flag in ctx.flags.iter() {
+        relations.push((&flag.name, &flag.requires, &flag.conflicts_with));
+    }
+    for (name, requires, conflicts_with) in relations {
+        if !__is_provided(name, tokens, config, ctx) {
+            continue;
+        }
+        for required in requires.iter() {
+            if __is_provided(required, tokens, config, ctx) {
+                continue;
+            }
+            let kind = match ctx.contains_arg(required) {
+                true => ErrorKind::MissingArgument,
+                false => ErrorKind::MissingFlag,
+            };
+            return Err(Error::new(
+                kind,
+                format!(
+                    "`{}` requires `{}`, which was not given",
+                    __member_usage(name, ctx),
+                    __member_usage(required, ctx)
+                ),
+            ));
+        }
+        for conflicting in conflicts_with.iter() {
+            if __is_provided(conflicting, tokens, config, ctx) {
+                return Err(Error::new(
+                    ErrorKind::ArgumentConflict,
+                    format!(
+                        "`{}` cannot be used together with `{}`",
+                        __member_usage(name, ctx),
+                        __member_usage(conflicting, ctx)
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn _check_conditional_requirements(
+    tokens: &Tokens,
+    config: &Config,
+    ctx: &Context,
+) -> Result<(), Error> {
+    let requirements = ctx
+        .args
+        .iter()
+        .map(|a| (&a.name, &a.required_if, &a.required_unless))
+        .chain(
+            ctx.flags
+                .iter()
+                .map(|f| (&f.name, &f.required_if, &f.required_unless)),
+        );
+    let provided = |name: &String| __is_provided(name, tokens, config, ctx);
+    for (name, required_if, required_unless) in requirements {
+        // a value provided outside of the command line satisfies the requirement
+        if provided(name) {
+            continue;
+        }
+        let reason: String = match required_if.iter().find(|n| provided(n)) {
+            Some(trigger) => format!("`{}` was given", __member_usage(trigger, ctx)),
+            None if !required_unless.is_empty() && !required_unless.iter().any(provided) => {
+                let candidates: Vec<String> = required_unless
+                    .iter()
+                    .map(|n| format!("`{}`", __member_usage(n, ctx)))
+                    .collect();
+                match candidates.len() {
+                    1 => format!("{} was not given", candidates[0]),
+                    _ => format!("none of {} was given", candidates.join(", ")),
+                }
+            }
+            None => continue,
+        };
+        let (kind, what) = match ctx.contains_arg(name) {
+            true => (ErrorKind::MissingArgument, "argument"),
+            false => (ErrorKind::MissingFlag, "flag"),
+        };
+        return Err(Error::new(
+            kind,
+            format!(
+                "Missing {} `{}`, which is required because {}",
+                what,
+                __member_usage(name, ctx),
+                reason
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Values read from a configuration file, keyed by the name of the flag
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Config {
+    /// The file the values were read from
+    pub(crate) path: PathBuf,
+    /// Every value given to each flag, along with the line it was given at
+    pub(crate) entries: HashMap<String, Vec<(String, usize)>>,
+}
+
+/// Finds the configuration file to read, if any. The file given through the
+/// config flag, on the command line or through its environment variable, must exist.
+///
+/// Otherwise, the first existing file is taken among the default value
+/// of the config flag and the search paths defined within Context
+fn __config_path(occurrences: &[Occurrence], ctx: &Context) -> Option<PathBuf> {
+    if let Some(flag) = ctx.config_flag.as_ref().and_then(|name| ctx.get_flag(name)) {
+        let given = occurrences
+            .iter()
+            .rev()
+            .find(|o| o.flag.name == flag.name)
+            .and_then(|o| o.value.clone());
+        if let Some(path) = given {
+            return Some(PathBuf::from(path));
+        }
+        if let Some(var) = flag.env.as_ref().filter(|_| __env_is_set(&flag.env)) {
+            return Some(PathBuf::from(std::env::var_os(var).unwrap()));
+        }
+        if let Some(def) = &flag.default {
+            let path = PathBuf::from(def);
+            if path.is_file() {
+                return Some(path);
+            }
+        }
+    }
+    ctx.config_paths.iter().find(|path| path.is_file()).cloned()
+}
+
+/// Cuts a comment off the end of a config value, which starts with either `#` or `;`
+/// after whitespace. Quoted values may contain either character
+fn __strip_comment(value: &str) -> &str {
+    let mut __quote: Option<char> = None;
+    let mut __previous: char = ' ';
+    for (idx, c) in value.char_indices() {
+        match (__quote, c) {
+            (Some(q), c) if c == q => __quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => __quote = Some(c),
+            (None, '#' | ';') if __previous.is_whitespace() => return &value[..idx],
+            (None, _) => {}
+        }
+        __previous = c;
+    }
+    value
+}
+
+/// Reads the configuration file, when the context defines one
+pub(crate) fn _load_config(occurrences: &[Occurrence], ctx: &Context) -> Result<Config, Error> {
+    let path: PathBuf = match __config_path(occurrences, ctx) {
+        Some(path) => path,
+        None => return Ok(Config::default()),
+    };
+    let content: String = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(error) => {
+            return Err(Error::new(
+                ErrorKind::Config,
+                format!("Could not read config file `{}`: {}", path.display(), error),
+            ));
+        }
+    };
+    let mut config = Config {
+        path,
+        entries: HashMap::new(),
+    };
+    /*
+        # comments start with either `#` or `;`
+        [server]
+        port = 8080 ; up to the end of the line
+        name = "my app"
+    */
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        // sections only organize the file, keys are flag names wherever they are
+        if line.starts_with('[') && line.ends_with(']') {
+            continue;
+        }
+        let (key, value) = match line.split_once('=') {
+            Some((key, value)) => (key.trim(), __strip_comment(value).trim()),
+            None => {
+                return Err(Error::new(
+                    ErrorKind::Config,
+                    format!(
+                        "Expected `key = value` in config file `{}` at line {}",
+                        config.path.display(),
+                        idx + 1
+                    ),
+                ));
+            }
+        };
+        // keys are the names of the flags, with or without their leading dashes
+        let bare: &str = key.trim_start_matches('-');
+        let flag: &Flag = match ctx
+            .flags
+            .iter()
+            .find(|f| f.name.trim_start_matches('-') == bare)
+        {
+            Some(flag) => flag,
+            None => {
+                let mut diagnosis = format!(
+                    "Unknown key `{}` in config file `{}` at line {}",
+                    key,
+                    config.path.display(),
+                    idx + 1
+                );
+                let candidates: Vec<String> = ctx
+                    .flags
+                    .iter()
+                    .map(|f| f.name.trim_start_matches('-').to_string())
+                    .collect();
+                if let Some(suggestion) = __suggest(bare, &candidates) {
+                    diagnosis += &format!("\n\n  tip: a similar key exists: `{}`", suggestion);
+                }
+                return Err(Error::new(ErrorKind::Config, diagnosis));
+            }
+        };
+        // values may be wrapped within quotes
+        let value: &str = match value.as_bytes() {
+            [b'"', .., b'"'] | [b'\'', .., b'\''] if value.len() >= 2 => &value[1..value.len() - 1],
+            _ => value,
+        };
+        config
+            .entries
+            .entry(flag.name.clone())
+            .or_default()
+            .push((value.to_string(), idx + 1));
+    }
+    Ok(config)
+}
+
+/// Parses a value read from a configuration file into its [`Value`]
+fn __parse_config_value(flag: &Flag, raw: &str, path: &Path, line: usize) -> Result<Value, Error> {
+    if let Type::Bool = flag.r#type {
+        return match __parse_bool(raw) {
+            Some(value) => Ok(Value::Bool(value)),
+            None => Err(Error::new(
+                ErrorKind::Config,
+                format!(
+                    "Ref: `{}`, {:?} is not a boolean, in config file `{}` at line {}",
+                    flag.name,
+                    raw,
+                    path.display(),
+                    line
+                ),
+            )),
+        };
+    }
+    match __parse_value(&flag.r#type, OsStr::new(raw), &flag.into(), &flag.name) {
+        Ok(value) => Ok(value),
+        Err(error) => Err(Error::new(
+            ErrorKind::Config,
+            format!(
+                "{}, in config file `{}` at line {}",
+                error.diagnosis(),
+                path.display(),
+                line
+            ),
+        )),
+    }
+}
+
+/// Distributes the positional arguments given among the arguments defined
+/// within Context, returning the range of positional arguments each one takes.
+///
+/// Fixed arguments are populated before a variadic argument, which takes whatever
+/// is left in between the arguments defined before and after it
+/// ```text
+///     CONTEXT ARGS: [SRC...,  DST]
+///       ARGS GIVEN: [a, b, c, d  ]
+///                    ^^^^^^^  ^^^
+///                       |      |
+///                    SRC...   DST
+/// ```
+fn __distribute_arguments(a_len: usize, ctx: &Context) -> Vec<Range<usize>> {
     let c_len = ctx.args.len();
-    if a_len >= c_len {
-        return Ok(());
+    let variadic: Option<usize> = ctx.args.iter().position(|arg| arg.variadic);
+    let before: usize = variadic.unwrap_or(c_len);
+    let after: usize = match variadic {
+        Some(idx) => c_len - idx - 1,
+        None => 0,
+    };
+    let lead_n = a_len.min(before);
+    let trail_n = (a_len - lead_n).min(after);
+    let var_n = a_len - lead_n - trail_n;
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for i in 0..before {
+        if i < lead_n {
+            ranges.push(i..i + 1);
+            continue;
+        }
+        ranges.push(i..i);
+    }
+    if variadic.is_some() {
+        ranges.push(lead_n..lead_n + var_n);
+    }
+    for i in 0..after {
+        let start = lead_n + var_n + i;
+        if i < trail_n {
+            ranges.push(start..start + 1);
+            continue;
+        }
+        ranges.push(start..start);
     }
+    ranges
+}
+
+pub(crate) fn _check_mandatory_args(args: &[OsString], ctx: &Context) -> Result<(), Error> {
+    let ranges = __distribute_arguments(args.len(), ctx);
     // meaning theres no sufficient args
     // to assign to every argument defined
     // within Context
@@ -93,12 +1075,12 @@ pub(crate) fn _check_mandatory_args(args: &[String], ctx: &Context) -> Result<()
                                    |
                              Missing Argument
     */
-    for i in a_len..c_len {
-        let arg = &ctx.args[i];
-        if arg.is_mandatory {
+    for (arg, range) in ctx.args.iter().zip(ranges) {
+        // an argument provided through its environment variable is not missing
+        if arg.is_mandatory && range.is_empty() && !__env_is_set(&arg.env) {
             return Err(Error::new(
                 ErrorKind::MissingArgument,
-                format!("Missing argument `<{}>`", arg.name.to_uppercase()),
+                format!("Missing argument `{}`", arg_usage(arg)),
             ));
         }
     }
@@ -106,9 +1088,17 @@ pub(crate) fn _check_mandatory_args(args: &[String], ctx: &Context) -> Result<()
     Ok(())
 }
 
-pub(crate) fn _check_mandatory_flags(args: &[String], ctx: &Context) -> Result<(), Error> {
+pub(crate) fn _check_mandatory_flags(
+    occurrences: &[Occurrence],
+    config: &Config,
+    ctx: &Context,
+) -> Result<(), Error> {
     for flag in ctx.flags.iter() {
-        if flag.is_mandatory && !args.contains(&flag.name) {
+        if flag.is_mandatory
+            && !occurrences.iter().any(|o| o.flag.name == flag.name)
+            && !__env_is_set(&flag.env)
+            && !config.entries.contains_key(&flag.name)
+        {
             return Err(Error::new(
                 ErrorKind::MissingFlag,
                 format!("Missing flag `<{}>`", flag.name.to_uppercase()),
@@ -118,131 +1108,272 @@ pub(crate) fn _check_mandatory_flags(args: &[String], ctx: &Context) -> Result<(
     Ok(())
 }
 
+pub(crate) fn _check_occurrences(
+    occurrences: &[Occurrence],
+    config: &Config,
+    ctx: &Context,
+) -> Result<(), Error> {
+    for flag in ctx.flags.iter() {
+        if !flag.multiple {
+            continue;
+        }
+        let mut count = occurrences
+            .iter()
+            .filter(|o| o.flag.name == flag.name)
+            .count();
+        // a flag missing from the command line takes the value of its environment
+        // variable, otherwise every value given within the configuration file
+        if count == 0 && __env_is_set(&flag.env) {
+            count = 1;
+        } else if count == 0 {
+            count = config.entries.get(&flag.name).map_or(0, Vec::len);
+        }
+        if let Some(min) = flag.min_occurrences {
+            if count < min {
+                return Err(Error::new(
+                    ErrorKind::TooFewOccurrences,
+                    format!(
+                        "Flag `{}` must be given at least {} time(s), but was given {}",
+                        flag.name, min, count
+                    ),
+                ));
+            }
+        }
+        if let Some(max) = flag.max_occurrences {
+            if count > max {
+                return Err(Error::new(
+                    ErrorKind::TooManyOccurrences,
+                    format!(
+                        "Flag `{}` may be given at most {} time(s), but was given {}",
+                        flag.name, max, count
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parses a boolean given outside of the command line, where unlike
+/// on the command line a boolean is switched on or off by its value
+fn __parse_bool(raw: &str) -> Option<bool> {
+    match raw.trim().to_lowercase().as_str() {
+        "true" | "1" | "yes" | "on" => Some(true),
+        "false" | "0" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+/// Signals if the given environment variable is set and not empty
+fn __env_is_set(env: &Option<String>) -> bool {
+    match env {
+        Some(var) => std::env::var_os(var).is_some_and(|value| !value.is_empty()),
+        None => false,
+    }
+}
+
+/// Parses the value of the given environment variable, if set and not empty
+fn __parse_env(
+    env: &Option<String>,
+    r#type: &Type,
+    constraints: &Constraints,
+    reference: &str,
+) -> Result<Option<Value>, Error> {
+    let var: &String = match env {
+        Some(var) if __env_is_set(env) => var,
+        _ => return Ok(None),
+    };
+    let raw: OsString = std::env::var_os(var).unwrap();
+    if let Type::Bool = r#type {
+        return match __parse_bool(&raw.to_string_lossy()) {
+            Some(value) => Ok(Some(Value::Bool(value))),
+            None => Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "Ref: `{}`, environment variable `{}` holds {:?}, which is not a boolean",
+                    reference, var, raw
+                ),
+            )),
+        };
+    }
+    match __parse_value(r#type, &raw, constraints, reference) {
+        Ok(value) => Ok(Some(value)),
+        Err(error) => Err(Error::new(
+            error.kind(),
+            format!("{}, from environment variable `{}`", error.diagnosis(), var),
+        )),
+    }
+}
+
+/// Parses a float, rejecting NaN and infinite numbers unless allowed
+fn __parse_float(raw: &str, constraints: &Constraints, reference: &str) -> Result<Value, Error> {
+    let float: f64 = match raw.parse::<f64>() {
+        Ok(float) => float,
+        Err(error) => {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("Ref: `{}`, {}", reference, error),
+            ));
+        }
+    };
+    if !float.is_finite() && !constraints.allow_non_finite {
+        return Err(Error::new(
+            ErrorKind::InvalidValue,
+            format!(
+                "Invalid value {:?} for `{}`: NaN and infinite numbers are not allowed",
+                raw, reference
+            ),
+        ));
+    }
+    Ok(Value::Float(float))
+}
+
+/// Parses a default value, which are represented as strings, into its [`Value`].
+///
+/// Defaults go through the same checks as the values given
+fn __parse_default(
+    r#type: &Type,
+    def: &str,
+    constraints: &Constraints,
+    reference: &str,
+) -> Result<Value, Error> {
+    let def: &str = &__check_value(def, constraints, reference)?;
+    match r#type {
+        Type::Str => Ok(Value::Str(def.to_owned())),
+        Type::Bool => {
+            let bind = def.trim();
+            let bind = bind.to_lowercase();
+            match bind.as_str() {
+                "true" => Ok(Value::Bool(true)),
+                "false" => Ok(Value::Bool(false)),
+                _ => Err(Error::new(
+                    ErrorKind::Other,
+                    format!("Ref: `{}`, unparsable default value {:?}", reference, bind),
+                )),
+            }
+        }
+        Type::Int | Type::Count => {
+            let int_result = def.parse::<i32>();
+            if int_result.is_err() {
+                return Err(Error::new(
+                    ErrorKind::Other,
+                    format!("Ref: `{}`, unparsable default value {:?}", reference, def),
+                ));
+            }
+            let int: i32 = int_result.unwrap();
+            __check_range(int, r#type, constraints, reference)?;
+            Ok(Value::Int(int))
+        }
+        Type::Float => __parse_float(def.trim(), constraints, reference),
+        Type::Path => Ok(Value::Path(PathBuf::from(def))),
+    }
+}
+
 pub(crate) fn _assign_arguments(
     parsed: &mut ParsedArguments,
-    args: &[String],
+    args: &[OsString],
     ctx: &Context,
 ) -> Result<(), Error> {
-    let a_len = args.len();
-    let c_len = ctx.args.len();
-    // meaning not every argument
-    // defined in Context is not
-    // going to get populated
-    if a_len < c_len {
-        // pointer is going to be used
-        // to continue where it left off
-        let mut ptr: usize = 0;
-        for i in 0..a_len {
-            let assigned_arg = &ctx.args[i];
-            match assigned_arg.r#type {
-                Type::Str => {
-                    let value = Value::Str(args[i].clone());
-                    parsed.arguments.insert(assigned_arg.name.clone(), value);
-                }
-                Type::Bool => {
-                    let value = Value::Bool(true);
-                    parsed.arguments.insert(assigned_arg.name.clone(), value);
-                }
-                Type::Int => {
-                    let int_parsed = args[i].parse::<i32>();
-                    if let Err(error) = int_parsed {
-                        return Err(Error::new(
-                            ErrorKind::Other,
-                            format!("Ref: `<{}>`, {}", assigned_arg.name.to_uppercase(), error),
-                        ));
-                    }
-                    let value = Value::Int(int_parsed.unwrap());
-                    parsed.arguments.insert(assigned_arg.name.clone(), value);
-                }
-            }
-            ptr += 1;
-        }
-        // Continuing where pointer left off
-        // No argument was provided for the defined
-        // arguments within context beyond this point
-        for i in ptr..c_len {
-            let assigned_arg = &ctx.args[i];
-            // parsing default value
-            if let Some(str_val) = &assigned_arg.default {
-                match assigned_arg.r#type {
-                    Type::Str => {
-                        let value = Value::Str(str_val.to_string());
-                        parsed.arguments.insert(assigned_arg.name.clone(), value);
-                    }
-                    Type::Bool => {
-                        let bind = str_val.trim();
-                        let bind = bind.to_lowercase();
-                        match bind.as_str() {
-                            "true" => {
-                                let value = Value::Bool(true);
-                                parsed.arguments.insert(assigned_arg.name.clone(), value);
-                            }
-                            "false" => {
-                                let value = Value::Bool(false);
-                                parsed.arguments.insert(assigned_arg.name.clone(), value);
-                            }
-                            _ => {
-                                return Err(Error::new(
-                                    ErrorKind::Other,
-                                    format!("Unparsable default value {:?}", bind),
-                                ))
-                            }
-                        }
-                    }
-                    Type::Int => {
-                        let int_parsed = args[i].parse::<i32>();
-                        if let Err(error) = int_parsed {
-                            return Err(Error::new(
-                                ErrorKind::Other,
-                                format!("Ref: `<{}>`, {}", assigned_arg.name.to_uppercase(), error),
-                            ));
-                        }
-                        let value = Value::Int(int_parsed.unwrap());
-                        parsed.arguments.insert(assigned_arg.name.clone(), value);
-                    }
-                }
-                // avoid using an else statement
+    let ranges = __distribute_arguments(args.len(), ctx);
+    for (assigned_arg, range) in ctx.args.iter().zip(ranges) {
+        let reference = arg_usage(assigned_arg);
+        // No argument was provided for the
+        // defined argument, parsing default value
+        if range.is_empty() {
+            let env = __parse_env(
+                &assigned_arg.env,
+                &assigned_arg.r#type,
+                &assigned_arg.into(),
+                &reference,
+            )?;
+            let value: Value = match (env, &assigned_arg.default) {
+                (Some(value), _) => value,
+                (None, Some(str_val)) => __parse_default(
+                    &assigned_arg.r#type,
+                    str_val,
+                    &assigned_arg.into(),
+                    &reference,
+                )?,
+                (None, None) => Value::Null,
+            };
+            // a variadic argument always holds a list of values,
+            // which is empty when nothing was given
+            if assigned_arg.variadic {
+                let list: Vec<Value> = match value.is_null() {
+                    true => Vec::new(),
+                    false => vec![value],
+                };
+                parsed
+                    .arguments
+                    .insert(assigned_arg.name.clone(), Value::List(list));
                 continue;
             }
+            parsed.arguments.insert(assigned_arg.name.clone(), value);
+            continue;
+        }
+        if assigned_arg.variadic {
+            let mut list: Vec<Value> = Vec::new();
+            for raw in args[range].iter() {
+                list.push(__parse_value(
+                    &assigned_arg.r#type,
+                    raw,
+                    &assigned_arg.into(),
+                    &reference,
+                )?);
+            }
             parsed
                 .arguments
-                .insert(assigned_arg.name.clone(), Value::Null);
+                .insert(assigned_arg.name.clone(), Value::List(list));
+            continue;
         }
+        let value = __parse_value(
+            &assigned_arg.r#type,
+            &args[range.start],
+            &assigned_arg.into(),
+            &reference,
+        )?;
+        parsed.arguments.insert(assigned_arg.name.clone(), value);
+    }
+    Ok(())
+}
+
+/// Deals with the positional arguments given beyond the ones defined within Context,
+/// which are either collected or rejected.
+///
+/// `offset` is the position of the first given argument within argv
+pub(crate) fn _assign_extra_arguments(
+    parsed: &mut ParsedArguments,
+    args: &[OsString],
+    positions: &[usize],
+    ctx: &Context,
+    offset: usize,
+) -> Result<(), Error> {
+    let ranges = __distribute_arguments(args.len(), ctx);
+    let consumed: usize = ranges.iter().map(|r| r.end).max().unwrap_or(0);
+    if consumed >= args.len() {
         return Ok(());
     }
-    // ELSE: arg length is greater than
-    // or equal to arguments length within Context
-    // Meaning every argument will get assigned
-    for i in 0..c_len {
-        let assigned_arg = &ctx.args[i];
-        match assigned_arg.r#type {
-            Type::Str => {
-                let value = Value::Str(args[i].clone());
-                parsed.arguments.insert(assigned_arg.name.clone(), value);
-            }
-            Type::Bool => {
-                let value = Value::Bool(true);
-                parsed.arguments.insert(assigned_arg.name.clone(), value);
-            }
-            Type::Int => {
-                let int_parsed = args[i].parse::<i32>();
-                if let Err(error) = int_parsed {
-                    return Err(Error::new(
-                        ErrorKind::Other,
-                        format!("Ref: `<{}>`, {}", assigned_arg.name.to_uppercase(), error),
-                    ));
-                }
-                let value = Value::Int(int_parsed.unwrap());
-                parsed.arguments.insert(assigned_arg.name.clone(), value);
-            }
-        }
+    if ctx.allow_extra_arguments {
+        parsed.extra_arguments = args[consumed..].to_vec();
+        return Ok(());
     }
-    Ok(())
+    let unexpected = &args[consumed].to_string_lossy();
+    let mut diagnosis = format!(
+        "Unexpected argument `{}` found at position {}",
+        unexpected,
+        offset + positions[consumed]
+    );
+    let candidates: Vec<String> = ctx.subcommands.iter().map(|s| s.name.clone()).collect();
+    if let Some(suggestion) = __suggest(unexpected, &candidates) {
+        diagnosis += &format!("\n\n  tip: a similar subcommand exists: `{}`", suggestion);
+    }
+    Err(Error::new(ErrorKind::UnexpectedArgument, diagnosis))
 }
 
 fn __assign_uncovered_flags(
     parsed: &mut ParsedArguments,
     ctx: &Context,
+    config: &Config,
     __covered_flags: &[&str],
 ) -> Result<(), Error> {
     // making use of the default value
@@ -251,44 +1382,49 @@ fn __assign_uncovered_flags(
         if __covered_flags.contains(&flag.name.as_str()) {
             continue;
         }
+        // the environment variable takes precedence over
+        // the configuration file, then the default value
+        if let Some(value) = __parse_env(&flag.env, &flag.r#type, &flag.into(), &flag.name)? {
+            if flag.multiple {
+                parsed
+                    .flags
+                    .insert(flag.name.clone(), Value::List(vec![value]));
+                continue;
+            }
+            parsed.flags.insert(flag.name.clone(), value);
+            continue;
+        }
+        if let Some(entries) = config.entries.get(&flag.name) {
+            let mut list: Vec<Value> = Vec::new();
+            for (raw, line) in entries.iter() {
+                list.push(__parse_config_value(flag, raw, &config.path, *line)?);
+            }
+            // a flag given more than once takes the last value, unless repeatable
+            if flag.multiple {
+                parsed.flags.insert(flag.name.clone(), Value::List(list));
+                continue;
+            }
+            parsed.flags.insert(flag.name.clone(), list.pop().unwrap());
+            continue;
+        }
         if let Some(def) = &flag.default {
-            match flag.r#type {
-                Type::Str => {
-                    let value = Value::Str(def.to_owned());
-                    parsed.flags.insert(flag.name.clone(), value);
-                }
-                Type::Bool => {
-                    let bind = def.trim();
-                    let bind = bind.to_lowercase();
-                    match bind.as_str() {
-                        "true" => {
-                            let value = Value::Bool(true);
-                            parsed.flags.insert(flag.name.clone(), value);
-                        }
-                        "false" => {
-                            let value = Value::Bool(true);
-                            parsed.flags.insert(flag.name.clone(), value);
-                        }
-                        _ => {
-                            return Err(Error::new(
-                                ErrorKind::Other,
-                                format!("Ref: `{}`, unparsable default value", &flag.name),
-                            ));
-                        }
-                    }
-                }
-                Type::Int => {
-                    let int_result = def.parse::<i32>();
-                    if int_result.is_err() {
-                        return Err(Error::new(
-                            ErrorKind::Other,
-                            format!("Ref: `{}`, unparsable default value", &flag.name),
-                        ));
-                    }
-                    let value = Value::Int(int_result.unwrap());
-                    parsed.flags.insert(flag.name.clone(), value);
-                }
+            let value = __parse_default(&flag.r#type, def, &flag.into(), &flag.name)?;
+            // a repeatable flag always holds a list of values
+            if flag.multiple {
+                parsed
+                    .flags
+                    .insert(flag.name.clone(), Value::List(vec![value]));
+                continue;
             }
+            parsed.flags.insert(flag.name.clone(), value);
+        } else if let Type::Count = flag.r#type {
+            // a counting flag that was never given was counted 0 times
+            parsed.flags.insert(flag.name.clone(), Value::Int(0));
+        } else if flag.multiple {
+            // a repeatable flag that was never given holds no value
+            parsed
+                .flags
+                .insert(flag.name.clone(), Value::List(Vec::new()));
         } else {
             parsed.flags.insert(flag.name.clone(), Value::Null);
         }
@@ -296,119 +1432,192 @@ fn __assign_uncovered_flags(
     Ok(())
 }
 
+/// What a value given to a flag or an argument is checked against
+struct Constraints<'a> {
+    validators: &'a [Validator],
+    possible_values: &'a [String],
+    ignore_case: bool,
+    min_value: Option<i32>,
+    max_value: Option<i32>,
+    allow_non_finite: bool,
+}
+
+impl<'a> From<&'a Flag> for Constraints<'a> {
+    fn from(flag: &'a Flag) -> Self {
+        Self {
+            validators: &flag.validators,
+            possible_values: &flag.possible_values,
+            ignore_case: flag.ignore_case,
+            min_value: flag.min_value,
+            max_value: flag.max_value,
+            allow_non_finite: flag.allow_non_finite,
+        }
+    }
+}
+
+impl<'a> From<&'a Arg> for Constraints<'a> {
+    fn from(arg: &'a Arg) -> Self {
+        Self {
+            validators: &arg.validators,
+            possible_values: &arg.possible_values,
+            ignore_case: arg.ignore_case,
+            min_value: arg.min_value,
+            max_value: arg.max_value,
+            allow_non_finite: arg.allow_non_finite,
+        }
+    }
+}
+
+/// Runs the checks of the flag or argument over the raw value given, returning
+/// the value spelled the way it was defined when matched regardless of case
+fn __check_value(raw: &str, constraints: &Constraints, reference: &str) -> Result<String, Error> {
+    let mut raw: String = raw.to_string();
+    if !constraints.possible_values.is_empty() {
+        let found = constraints
+            .possible_values
+            .iter()
+            .find(|v| match constraints.ignore_case {
+                true => v.to_lowercase() == raw.to_lowercase(),
+                false => **v == raw,
+            });
+        match found {
+            Some(value) => raw = value.clone(),
+            None => {
+                let mut diagnosis = format!(
+                    "Invalid value {:?} for `{}`, possible values: {}",
+                    raw,
+                    reference,
+                    constraints.possible_values.join(", ")
+                );
+                if let Some(suggestion) = __suggest(&raw, constraints.possible_values) {
+                    diagnosis += &format!("\n\n  tip: a similar value exists: `{}`", suggestion);
+                }
+                return Err(Error::new(ErrorKind::InvalidValue, diagnosis));
+            }
+        }
+    }
+    for validator in constraints.validators.iter() {
+        if let Err(message) = (validator.0)(&raw) {
+            return Err(Error::new(
+                ErrorKind::InvalidValue,
+                format!("Invalid value {:?} for `{}`: {}", raw, reference, message),
+            ));
+        }
+    }
+    Ok(raw)
+}
+
+/// Rejects an integer out of the bounds of the flag or argument
+fn __check_range(
+    int: i32,
+    r#type: &Type,
+    constraints: &Constraints,
+    reference: &str,
+) -> Result<(), Error> {
+    // bounds only apply to `Type::Int`
+    let below: bool = constraints.min_value.is_some_and(|min| int < min);
+    let above: bool = constraints.max_value.is_some_and(|max| int > max);
+    if matches!(r#type, Type::Int) && (below || above) {
+        return Err(Error::new(
+            ErrorKind::OutOfRange,
+            format!(
+                "Value {} for `{}` is out of range, expected {}",
+                int,
+                reference,
+                range_usage(constraints.min_value, constraints.max_value)
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Parses a raw value given into its [`Value`], once checked
+fn __parse_value(
+    r#type: &Type,
+    raw: &OsStr,
+    constraints: &Constraints,
+    reference: &str,
+) -> Result<Value, Error> {
+    if let Type::Path = r#type {
+        // paths may hold non UTF-8 data, which is replaced for the checks
+        __check_value(&raw.to_string_lossy(), constraints, reference)?;
+        return Ok(Value::Path(PathBuf::from(raw)));
+    }
+    // every other type must be valid UTF-8
+    let raw: &str = match raw.to_str() {
+        Some(raw) => raw,
+        None => {
+            return Err(Error::new(
+                ErrorKind::InvalidUtf8,
+                format!(
+                    "Ref: `{}`, invalid UTF-8 in {:?}",
+                    reference,
+                    raw.to_string_lossy()
+                ),
+            ))
+        }
+    };
+    let raw: &str = &__check_value(raw, constraints, reference)?;
+    match r#type {
+        Type::Str => Ok(Value::Str(raw.to_string())),
+        Type::Bool => Ok(Value::Bool(true)),
+        Type::Int | Type::Count => {
+            let int_result = raw.parse::<i32>();
+            if let Err(error) = int_result {
+                return Err(Error::new(
+                    ErrorKind::Other,
+                    format!("Ref: `{}`, {}", reference, error),
+                ));
+            }
+            let int: i32 = int_result.unwrap();
+            __check_range(int, r#type, constraints, reference)?;
+            Ok(Value::Int(int))
+        }
+        Type::Float => __parse_float(raw, constraints, reference),
+        Type::Path => Ok(Value::Path(PathBuf::from(raw))),
+    }
+}
+
 pub(crate) fn _assign_options(
     parsed: &mut ParsedArguments,
-    fv: &[String],
+    occurrences: &[Occurrence],
+    config: &Config,
     ctx: &Context,
 ) -> Result<(), Error> {
-    let mut __pointer: usize = 0;
     let mut __covered_flags: Vec<&str> = Vec::new();
-    let fv_len = fv.len();
-    if fv_len == 0 {
-        // if there are no flags present, but flags
-        // were defined in the Context, the following,
-        // will assign such values with their default values
-        __assign_uncovered_flags(parsed, ctx, &__covered_flags)?;
-    }
-    while __pointer < fv_len {
-        if let Some(flag) = ctx.get_flag(&fv[__pointer]) {
-            let bind = &fv[__pointer];
-            __covered_flags.push(bind);
-            match flag.r#type {
-                Type::Str => {
-                    if __pointer + 1 >= fv_len {
-                        return Err(Error::new(
-                            ErrorKind::MissingValue,
-                            format!("Missing value for `{}`.", flag.name),
-                        ));
-                    }
-                    // means value is a defined flag
-                    if ctx.contains_flag(&fv[__pointer + 1]) {
-                        let next_iter = &fv[__pointer + 1];
-                        return Err(Error::new(
-                            ErrorKind::MissingValue,
-                            format!(
-                                "Missing value for `{}`. \
-if you want to pass `{}` as the value, try `-- {}`.",
-                                flag.name, next_iter, next_iter
-                            ),
-                        ));
-                    }
-                    // if next iteration's value is "--"
-                    if &fv[__pointer + 1] == "--" {
-                        // Lets say the next iteration was "--", but no value was provided after
-                        if __pointer + 2 >= fv_len {
-                            return Err(Error::new(
-                                ErrorKind::MissingValue,
-                                format!("Missing value for `{}`", &flag.name),
-                            ));
-                        }
-                        let value = Value::Str(fv[__pointer + 2].clone());
-                        parsed.flags.insert(flag.name, value);
-                        __pointer += 3;
-                        continue;
-                    }
-                    let value = Value::Str(fv[__pointer + 1].clone());
-                    parsed.flags.insert(flag.name, value);
-                    __pointer += 2;
-                }
-                Type::Bool => {
-                    let value = Value::Bool(true);
-                    parsed.flags.insert(flag.name, value);
-                    __pointer += 1;
-                }
-                Type::Int => {
-                    if __pointer + 1 >= fv_len {
-                        return Err(Error::new(
-                            ErrorKind::MissingValue,
-                            format!("Missing value for `{}`.", flag.name),
-                        ));
-                    }
-                    // means value is a defined flag
-                    if ctx.contains_flag(&fv[__pointer + 1]) {
-                        let next_iter = &fv[__pointer + 1];
-                        return Err(Error::new(
-                            ErrorKind::MissingValue,
-                            format!(
-                                "Missing value for `{}`. \
-if you want to pass `{}` as the value, try `-- {}`.",
-                                flag.name, next_iter, next_iter
-                            ),
-                        ));
-                    }
-                    if &fv[__pointer + 1] == "--" {
-                        if __pointer + 2 >= fv_len {
-                            return Err(Error::new(
-                                ErrorKind::MissingValue,
-                                format!("Missing value for `{}`", &flag.name),
-                            ));
-                        }
-                        let int_result = fv[__pointer + 2].parse::<i32>();
-                        if let Err(error) = int_result {
-                            return Err(Error::new(
-                                ErrorKind::Other,
-                                format!("Ref: `{}`, {}", &flag.name, error),
-                            ));
-                        }
-                        let value = Value::Int(int_result.unwrap());
-                        parsed.flags.insert(flag.name, value);
-                        __pointer += 3;
-                        continue;
-                    }
-                    let int_result = fv[__pointer + 1].parse::<i32>();
-                    if let Err(error) = int_result {
-                        return Err(Error::new(
-                            ErrorKind::Other,
-                            format!("Ref: `{}`, {}", &flag.name, error),
-                        ));
-                    }
-                    let value = Value::Int(int_result.unwrap());
-                    parsed.flags.insert(flag.name, value);
-                    __pointer += 2;
-                }
+    for occurrence in occurrences.iter() {
+        let flag = &occurrence.flag;
+        __covered_flags.push(&flag.name);
+        if let Type::Count = flag.r#type {
+            let mut count: i32 = match parsed.flags.get(&flag.name) {
+                Some(Value::Int(so_far)) => so_far + 1,
+                _ => 1,
+            };
+            if let Some(max) = flag.max_count {
+                count = count.min(max);
+            }
+            parsed.flags.insert(flag.name.clone(), Value::Int(count));
+            continue;
+        }
+        let value = match &occurrence.value {
+            Some(raw) => __parse_value(&flag.r#type, raw, &flag.into(), &flag.name)?,
+            // only `Type::Bool` flags come without a value,
+            // the last one given wins over the previous ones
+            None => Value::Bool(!occurrence.negated),
+        };
+        if flag.multiple {
+            // appending to the values collected so far
+            if let Some(Value::List(list)) = parsed.flags.get_mut(&flag.name) {
+                list.push(value);
+                continue;
             }
+            parsed
+                .flags
+                .insert(flag.name.clone(), Value::List(vec![value]));
+            continue;
         }
+        parsed.flags.insert(flag.name.clone(), value);
     }
-    __assign_uncovered_flags(parsed, ctx, &__covered_flags)?;
+    __assign_uncovered_flags(parsed, ctx, config, &__covered_flags)?;
     Ok(())
 }
@@ -2,63 +2,227 @@ mod builder;
 pub(crate) mod funcs;
 
 use crate::errors::Error;
-use crate::get_env_args;
 use crate::Context;
 
 pub use builder::ParsedArguments;
 pub use builder::Value;
 
-use std::sync::LazyLock;
+use std::env::args_os;
+use std::ffi::OsString;
 
-static ARGUMENTS: LazyLock<Vec<String>> = LazyLock::new(|| {
-    // getting rid of the argument containing the file name because
-    // it will only cause trouble in the long run
-    let bind = get_env_args();
-    match bind.len() {
-        1 => Vec::new(),
-        2 | _ => bind[1..].to_vec(),
+/// Obtains the name the program was invoked with, without
+/// panicking on non UTF-8 data
+fn _program_name() -> String {
+    match args_os().next() {
+        Some(name) => name.to_string_lossy().to_string(),
+        None => String::new(),
     }
-});
+}
 
-fn _unwrap_or_throw<R>(variant: Result<R, Error>, ctx: &Context) -> R {
+fn _unwrap_or_throw<R>(variant: Result<R, Error>, ctx: &Context, bin: &str) -> R {
     use crate::help::send_help_and_exit;
 
     if let Err(error) = variant {
-        send_help_and_exit(ctx, &error);
+        send_help_and_exit(ctx, bin, &error);
     }
     variant.unwrap()
 }
 
-/// # Main Function to be executed when parsin the CLI arguments
-pub fn parse(__ctx: &Context) -> ParsedArguments {
+/// Runs the parsing pipeline over the given arguments,
+/// which must NOT contain the program name.
+///
+/// `__offset` is the position of the first given argument within argv
+fn _try_parse(
+    __ctx: &Context,
+    __bin: &str,
+    __arguments: &[OsString],
+    __offset: usize,
+) -> Result<ParsedArguments, Error> {
+    // relations may refer to flags and arguments added after them,
+    // thus are only checked once the context is complete
+    __ctx.validate()?;
+    // `@path` arguments are expanded before anything else,
+    // so that response files may contain any argument
+    let __expanded = funcs::_expand_response_files(__arguments, __ctx)?;
+    let __arguments: &[OsString] = &__expanded;
     {
         // Checking for the `--help` flag.
         // Looking for an early return
-        _unwrap_or_throw(funcs::_look_for_help(&ARGUMENTS), __ctx);
+        funcs::_look_for_help(__arguments, __ctx, __bin)?;
     }
     let mut __parsed = ParsedArguments::new();
-    let mut __args: Vec<String> = Vec::new();
-    let mut __flags_and_values: Vec<String> = Vec::new();
+    let __tokens = funcs::_arguments_and_options(__arguments, __ctx)?;
     {
-        (__args, __flags_and_values) = funcs::_arguments_and_options(&ARGUMENTS, __ctx);
+        funcs::_check_mandatory_args(&__tokens.args, __ctx)?;
     }
+    // values missing from the command line may be found within the configuration file
+    let __config = funcs::_load_config(&__tokens.occurrences, __ctx)?;
     {
-        _unwrap_or_throw(funcs::_check_mandatory_args(&__args, __ctx), __ctx);
+        funcs::_check_mandatory_flags(&__tokens.occurrences, &__config, __ctx)?;
     }
     {
-        _unwrap_or_throw(funcs::_check_mandatory_flags(&ARGUMENTS, __ctx), __ctx);
+        funcs::_check_conditional_requirements(&__tokens, &__config, __ctx)?;
     }
     {
-        _unwrap_or_throw(
-            funcs::_assign_arguments(&mut __parsed, &__args, __ctx),
-            __ctx,
-        );
+        funcs::_check_occurrences(&__tokens.occurrences, &__config, __ctx)?;
+    }
+    {
+        funcs::_check_groups(&__tokens, __ctx)?;
     }
     {
-        _unwrap_or_throw(
-            funcs::_assign_options(&mut __parsed, &__flags_and_values, __ctx),
+        funcs::_check_relations(&__tokens, &__config, __ctx)?;
+    }
+    {
+        funcs::_assign_arguments(&mut __parsed, &__tokens.args, __ctx)?;
+    }
+    {
+        funcs::_assign_extra_arguments(
+            &mut __parsed,
+            &__tokens.args,
+            &__tokens.positions,
             __ctx,
-        );
+            __offset,
+        )?;
+    }
+    {
+        funcs::_assign_options(&mut __parsed, &__tokens.occurrences, &__config, __ctx)?;
+    }
+    __parsed.trailing = __tokens.trailing;
+    __parsed.unknown_flags = __tokens.unknown_flags;
+    if let Some((name, idx)) = __tokens.subcommand {
+        // the subcommand is parsed with its own context, recursively
+        let __sub = __ctx.get_subcommand(&name).unwrap();
+        let __sub_bin = format!("{} {}", __bin, name);
+        let __rest = &__arguments[idx + 1..];
+        let __sub_parsed = _try_parse(&__sub.context, &__sub_bin, __rest, __offset + idx + 1)?;
+        __parsed.subcommand = Some((name, Box::new(__sub_parsed)));
     }
-    __parsed
+    Ok(__parsed)
+}
+
+/// Splits the program name from the rest of the arguments
+fn _split_argv<I, T>(argv: I) -> (String, Vec<OsString>)
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString>,
+{
+    let mut __argv = argv.into_iter().map(Into::into);
+    // getting rid of the argument containing the file name because
+    // it will only cause trouble in the long run
+    let __bin: String = match __argv.next() {
+        Some(name) => name.to_string_lossy().to_string(),
+        None => _program_name(),
+    };
+    (__bin, __argv.collect())
+}
+
+/// # Main Function to be executed when parsin the CLI arguments
+///
+/// Parses the arguments the process was started with,
+/// see [`parse_from`] to parse arbitrary arguments instead.
+///
+/// On failure, the error is displayed along with a hint
+/// and the process exits, see [`try_parse`] to handle the error yourself.
+pub fn parse(__ctx: &Context) -> ParsedArguments {
+    parse_from_argv(__ctx, crate::get_env_args_os())
+}
+
+/// Parses the given arguments instead of the ones the process was started with.
+///
+/// The arguments must **not** include the program name, use [`parse_from_argv`]
+/// when the first item is the program name.
+/// ```rust
+/// use parsin::{Context, Type};
+/// use parsin::parser::parse_from;
+///
+/// # fn main() {
+/// let ctx = Context::from((
+///     &[("name", Type::Str, "Your name", true, None)],
+///     &[("--repeat", Type::Int, "The amount of times to greet", false, Some("1"))],
+/// ));
+/// let parsed = parse_from(&ctx, ["John", "--repeat", "3"]);
+/// assert_eq!(parsed.arguments["name"].unwrap_str(), "John");
+/// assert_eq!(*parsed.flags["--repeat"].unwrap_int(), 3);
+/// # }
+/// ```
+pub fn parse_from<I, T>(__ctx: &Context, args: I) -> ParsedArguments
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString>,
+{
+    let __bin = _program_name();
+    let __arguments: Vec<OsString> = args.into_iter().map(Into::into).collect();
+    _unwrap_or_throw(_try_parse(__ctx, &__bin, &__arguments, 1), __ctx, &__bin)
+}
+
+/// Same as [`parse_from`], except that the first item is taken as the
+/// program name, which is displayed within the help message
+/// ```rust
+/// use parsin::{Context, Type};
+/// use parsin::parser::parse_from_argv;
+///
+/// # fn main() {
+/// let ctx = Context::from((
+///     &[("name", Type::Str, "Your name", true, None)],
+///     &[("--loud", Type::Bool, "Greets loudly", false, None)],
+/// ));
+/// let parsed = parse_from_argv(&ctx, ["greet", "John", "--loud"]);
+/// assert_eq!(parsed.arguments["name"].unwrap_str(), "John");
+/// assert!(*parsed.flags["--loud"].unwrap_bool());
+/// # }
+/// ```
+pub fn parse_from_argv<I, T>(__ctx: &Context, argv: I) -> ParsedArguments
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString>,
+{
+    let (__bin, __arguments) = _split_argv(argv);
+    _unwrap_or_throw(_try_parse(__ctx, &__bin, &__arguments, 1), __ctx, &__bin)
+}
+
+/// Same as [`parse`], except that the process never exits on its own.
+///
+/// Any failure, including the client passing `--help`, is handed back as an
+/// [`Error`]. In the case of [`ErrorKind::WantsHelp`](enum@crate::errors::ErrorKind),
+/// the diagnosis contains the rendered help message.
+pub fn try_parse(__ctx: &Context) -> Result<ParsedArguments, Error> {
+    try_parse_from_argv(__ctx, crate::get_env_args_os())
+}
+
+/// Non-exiting version of [`parse_from`]
+/// ```rust
+/// use parsin::{Context, Type};
+/// use parsin::errors::ErrorKind;
+/// use parsin::parser::try_parse_from;
+///
+/// # fn main() {
+/// let ctx = Context::from((
+///     &[("name", Type::Str, "Your name", true, None)],
+///     &[("--repeat", Type::Int, "The amount of times to greet", false, Some("1"))],
+/// ));
+/// let error = try_parse_from(&ctx, ["--repeat", "3"]).unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::MissingArgument);
+///
+/// let error = try_parse_from(&ctx, ["--help"]).unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::WantsHelp);
+/// assert!(error.diagnosis().contains("--repeat"));
+/// # }
+/// ```
+pub fn try_parse_from<I, T>(__ctx: &Context, args: I) -> Result<ParsedArguments, Error>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString>,
+{
+    let __arguments: Vec<OsString> = args.into_iter().map(Into::into).collect();
+    _try_parse(__ctx, &_program_name(), &__arguments, 1)
+}
+
+/// Non-exiting version of [`parse_from_argv`]
+pub fn try_parse_from_argv<I, T>(__ctx: &Context, argv: I) -> Result<ParsedArguments, Error>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString>,
+{
+    let (__bin, __arguments) = _split_argv(argv);
+    _try_parse(__ctx, &__bin, &__arguments, 1)
 }